@@ -25,6 +25,14 @@ fn main() -> Result<(), Box<dyn Error>> {
         Path::new(&manifest_dir).join("lib")
     };
 
+    // No released core library exports the functions bound by the `core-preview` feature
+    if env::var("CARGO_FEATURE_CORE_PREVIEW").is_ok() && env::var("ASPOSE_PDF_LIB_DIR").is_err() {
+        println!(
+            "cargo:warning=the core-preview feature is unusable with the bundled library: \
+             it does not export the preview functions, so linking will fail"
+        );
+    }
+
     // Ensure lib directory exists
     if !lib_dir.exists() {
         return Err(format!("Library directory not found: {}", lib_dir.display()).into());
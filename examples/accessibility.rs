@@ -0,0 +1,28 @@
+use asposepdf::Document;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Tag the PDF-document and set its language
+    pdf.auto_tag()?;
+    pdf.set_language("en-US")?;
+
+    // Describe the images of the first page
+    for image in pdf.images(1)? {
+        pdf.set_image_alt_text(&image.id, "Company logo")?;
+    }
+
+    // Print the remaining PDF/UA issues
+    for issue in pdf.validate_pdfua()?.issues {
+        println!(
+            "{} {:?}: {}",
+            issue.checkpoint, issue.check, issue.description
+        );
+    }
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_accessible.pdf")?;
+
+    Ok(())
+}
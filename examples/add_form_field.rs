@@ -0,0 +1,33 @@
+use asposepdf::{Document, FieldDefinition, Rect};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create a new PDF-document with one page
+    let pdf = Document::new()?;
+    pdf.page_add()?;
+
+    // Add a required text field, a check box and a combo box to the first page
+    pdf.add_form_field(
+        1,
+        &FieldDefinition::text("name", Rect::new(100.0, 700.0, 300.0, 720.0))
+            .required()
+            .with_tooltip("Full name"),
+    )?;
+    pdf.add_form_field(
+        1,
+        &FieldDefinition::checkbox("agree", Rect::new(100.0, 660.0, 115.0, 675.0)),
+    )?;
+    pdf.add_form_field(
+        1,
+        &FieldDefinition::combo_box(
+            "country",
+            Rect::new(100.0, 620.0, 300.0, 640.0),
+            &["DE", "FR", "US"],
+        )
+        .with_default_value("US"),
+    )?;
+
+    // Save the PDF-document as "form.pdf"
+    pdf.save_as("form.pdf")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use asposepdf::{Annotation, Document, Rect};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Print reviewer comments on the first page
+    for annotation in pdf.annotations(1)? {
+        println!(
+            "#{} {:?} by {}: {}",
+            annotation.index,
+            annotation.kind,
+            annotation.author.as_deref().unwrap_or("unknown"),
+            annotation.contents.as_deref().unwrap_or("")
+        );
+    }
+
+    // Add a text note and a highlight to the first page
+    let rect = Rect::new(100.0, 700.0, 300.0, 720.0);
+    pdf.add_annotation(
+        1,
+        &Annotation::text(rect, "Looks good").with_author("Reviewer"),
+    )?;
+    pdf.add_annotation(1, &Annotation::highlight(rect).with_color("#FFFF00"))?;
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_annotations.pdf")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use asposepdf::Document;
+use std::fs;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Embed an XML-file in the PDF-document
+    let xml = fs::read("invoice.xml")?;
+    pdf.add_attachment("invoice.xml", &xml, "text/xml", "E-invoice data")?;
+
+    // Print the list of embedded files and extract each of them
+    for attachment in pdf.attachments()? {
+        println!(
+            "{} ({} bytes, {})",
+            attachment.name,
+            attachment.size,
+            attachment.mime_type.as_deref().unwrap_or("unknown type")
+        );
+        let data = pdf.extract_attachment(&attachment.name)?;
+        fs::write(format!("extracted_{}", attachment.name), data)?;
+    }
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_attachments.pdf")?;
+
+    Ok(())
+}
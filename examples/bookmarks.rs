@@ -0,0 +1,34 @@
+use asposepdf::{Bookmark, Document};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Print the outline as an indented tree
+    fn print_tree(bookmarks: &[Bookmark], level: usize) {
+        for bookmark in bookmarks {
+            println!(
+                "{}{} (page {})",
+                "  ".repeat(level),
+                bookmark.title,
+                bookmark.page
+            );
+            print_tree(&bookmark.children, level + 1);
+        }
+    }
+    print_tree(&pdf.bookmarks()?, 0);
+
+    // Add a top-level bookmark with a child
+    pdf.add_bookmark(
+        &[],
+        Bookmark::new("Summary", 1).with_child(Bookmark::new("Details", 1)),
+    )?;
+
+    // Export the outline to a JSON-file
+    pdf.export_bookmarks("sample_bookmarks.json")?;
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_bookmarks.pdf")?;
+
+    Ok(())
+}
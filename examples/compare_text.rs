@@ -0,0 +1,24 @@
+use asposepdf::{Document, TextChange};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open the original and the revised PDF-documents with filenames
+    let original = Document::open("contract_v1.pdf")?;
+    let revised = Document::open("contract_v2.pdf")?;
+
+    // Compare the texts and print the changes
+    let diff = Document::compare_text(&original, &revised)?;
+    for change in &diff.changes {
+        match change {
+            TextChange::Insert { new } => println!("page {}: + {:?}", new.page, new.text),
+            TextChange::Delete { old } => println!("page {}: - {:?}", old.page, old.text),
+            TextChange::Modify { old, new } => {
+                println!("page {}: {:?} -> {:?}", new.page, old.text, new.text)
+            }
+        }
+    }
+
+    // Save the redline as "contract_redline.pdf"
+    revised.save_text_comparison(&diff, "contract_redline.pdf")?;
+
+    Ok(())
+}
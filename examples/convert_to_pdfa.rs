@@ -0,0 +1,28 @@
+use asposepdf::{Document, PdfAFormat};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Convert the PDF-document to PDF/A-2b and print the lossy fixes
+    let report = pdf.convert_to_pdfa(PdfAFormat::A2b)?;
+    for fix in report.lossy_fixes() {
+        println!(
+            "{} ({}): {}",
+            fix.clause,
+            fix.page.unwrap_or(0),
+            fix.description
+        );
+    }
+
+    // Check the result before archiving
+    let validation = pdf.validate_pdfa(PdfAFormat::A2b)?;
+    for violation in &validation.violations {
+        println!("{}: {}", violation.clause, violation.description);
+    }
+    if validation.is_compliant() {
+        pdf.save_as("sample_pdfa.pdf")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use asposepdf::Document;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample_xfa.pdf")?;
+
+    if pdf.has_xfa()? {
+        // Keep a copy of the XFA XML packet
+        if let Some(xdp) = pdf.xfa_data()? {
+            std::fs::write("sample_xfa.xdp", xdp)?;
+        }
+
+        // Convert the XFA form to a static AcroForm
+        let report = pdf.convert_xfa_to_acroform()?;
+        println!("Converted fields: {:?}", report.converted_fields);
+        for dropped in &report.dropped_fields {
+            println!("Dropped field {}: {}", dropped.name, dropped.reason);
+        }
+
+        // Save the previously opened PDF-document with new filename
+        pdf.save_as("sample_acroform.pdf")?;
+    }
+
+    Ok(())
+}
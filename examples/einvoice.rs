@@ -0,0 +1,26 @@
+use asposepdf::{Document, FacturXProfile};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open the invoice PDF-document and the EN 16931 XML with filenames
+    let pdf = Document::open("invoice.pdf")?;
+    let xml = std::fs::read_to_string("factur-x.xml")?;
+
+    // Embed the XML and convert the invoice to PDF/A-3b
+    let report = pdf.embed_einvoice(&xml, FacturXProfile::En16931)?;
+    if !report.is_compliant() {
+        for violation in &report.violations {
+            println!("{}: {}", violation.clause, violation.description);
+        }
+    }
+
+    // Save the e-invoice as "invoice_facturx.pdf"
+    pdf.save_as("invoice_facturx.pdf")?;
+
+    // Read the XML back
+    let incoming = Document::open("invoice_facturx.pdf")?;
+    if let Some(invoice) = incoming.extract_einvoice()? {
+        println!("{} ({:?})", invoice.file_name, invoice.profile);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use asposepdf::{Document, ImageEncoding};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Extract the images of every page in their native encoding
+    for num in 1..=pdf.page_count()? {
+        for (i, image) in pdf.images(num)?.iter().enumerate() {
+            println!(
+                "Page {num}: {}x{} {} {:?}, {:.0}x{:.0} DPI",
+                image.width,
+                image.height,
+                image.color_space,
+                image.filters,
+                image.dpi_x,
+                image.dpi_y
+            );
+            let data = pdf.extract_image(&image.id, ImageEncoding::Native)?;
+            std::fs::write(
+                format!("sample_page{num}_image{i}.{}", image.native_extension()),
+                data,
+            )?;
+        }
+    }
+
+    Ok(())
+}
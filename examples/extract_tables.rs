@@ -0,0 +1,13 @@
+use asposepdf::Document;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Extract the tables of all pages and save each as CSV
+    for (i, table) in pdf.extract_tables("1-")?.iter().enumerate() {
+        std::fs::write(format!("sample_table{i}.csv"), table.to_csv())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use asposepdf::Document;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Employee {
+    name: String,
+    email: String,
+    start_date: String,
+    remote: bool,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with AcroForm
+    let pdf = Document::open("sample_form.pdf")?;
+
+    // Print AcroForm fields
+    for field in pdf.form_fields()? {
+        println!("{} ({:?}): {:?}", field.name, field.field_type, field.value);
+    }
+
+    // Fill fields from a record and flatten the PDF-document
+    let employee = Employee {
+        name: "John Doe".to_string(),
+        email: "john.doe@example.com".to_string(),
+        start_date: "2025-01-31".to_string(),
+        remote: true,
+    };
+    pdf.fill_form(&employee, true)?;
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_form_filled.pdf")?;
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use asposepdf::{Document, FlattenOptions};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample_form.pdf")?;
+
+    // Lock the signed-off fields and keep the rest of the form fillable
+    pdf.flatten_with_options(
+        &FlattenOptions::fields(&["approved_by", "approval_date"]).with_regenerated_appearances(),
+    )?;
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_form_locked.pdf")?;
+
+    Ok(())
+}
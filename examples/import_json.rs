@@ -0,0 +1,18 @@
+use asposepdf::Document;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with AcroForm
+    let pdf = Document::open("sample_form.pdf")?;
+
+    // Import data from JSON-file keyed by field name into AcroForm fields
+    let report = pdf.import_json("sample_form.json")?;
+    if !report.is_complete() {
+        println!("Unknown fields: {:?}", report.unknown_fields);
+        println!("Invalid values: {:?}", report.invalid_values);
+    }
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_form_import_json.pdf")?;
+
+    Ok(())
+}
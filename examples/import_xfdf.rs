@@ -0,0 +1,24 @@
+use asposepdf::Document;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with AcroForm
+    let pdf = Document::open("sample_form.pdf")?;
+
+    // Import data from XFDF-file into AcroForm fields
+    let report = pdf.import_xfdf("sample_form.xfdf")?;
+
+    // Print the import report
+    println!("Imported: {:?}", report.imported);
+    println!("Unknown fields: {:?}", report.unknown_fields);
+    for invalid in &report.invalid_values {
+        println!(
+            "Invalid value {:?} for {}: {}",
+            invalid.value, invalid.field, invalid.reason
+        );
+    }
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_form_import_xfdf.pdf")?;
+
+    Ok(())
+}
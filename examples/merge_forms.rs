@@ -0,0 +1,23 @@
+use asposepdf::{Document, MergeOptions};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open the filled forms with filenames
+    let pdf1 = Document::open("form_alice.pdf")?;
+    let pdf2 = Document::open("form_bob.pdf")?;
+
+    // Merge the forms, prefixing conflicting field names with a label per form
+    let (merged, renames) = Document::merge_documents_with_options(
+        &[&pdf1, &pdf2],
+        &MergeOptions::prefix()
+            .with_labels(&["alice", "bob"])
+            .only_conflicting(),
+    )?;
+    for rename in &renames {
+        println!("{} -> {}", rename.old_name, rename.new_name);
+    }
+
+    // Save the merged PDF-document as "forms_merged.pdf"
+    merged.save_as("forms_merged.pdf")?;
+
+    Ok(())
+}
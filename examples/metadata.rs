@@ -0,0 +1,26 @@
+use asposepdf::{Document, PdfDate};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Return the PDF-document metadata
+    let mut metadata = pdf.metadata()?;
+
+    // Print metadata fields
+    println!("{}", metadata);
+
+    // Update metadata fields
+    metadata.title = Some("Annual Report".to_string());
+    metadata.author = Some("Finance Department".to_string());
+    metadata.mod_date = Some(PdfDate::new(2025, 1, 31, 12, 0, 0)?);
+    pdf.set_metadata(&metadata)?;
+
+    // Set a property in a custom XMP namespace
+    pdf.set_xmp_property("http://example.com/archive/1.0/", "arc", "RecordId", "A-42")?;
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_metadata.pdf")?;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use asposepdf::{Document, TileOptions};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Save the deep-zoom tiles of the first page at 2x zoom as "tiles/page1.dzi" and "tiles/page1_files/"
+    let options = TileOptions {
+        zoom: 2.0,
+        ..Default::default()
+    };
+    let pyramid = pdf.save_page_tiles(1, &options, "tiles", "page1")?;
+    println!(
+        "{}x{} pixels, {} levels",
+        pyramid.width,
+        pyramid.height,
+        pyramid.max_level() + 1
+    );
+
+    Ok(())
+}
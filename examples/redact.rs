@@ -0,0 +1,32 @@
+use asposepdf::{Document, Rect, RedactionAppearance, RedactionMark, RedactionPattern};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Mark SSNs, e-mail addresses and an area on the first page for redaction
+    let marks = [
+        RedactionMark::pattern(RedactionPattern::Ssn),
+        RedactionMark::pattern(RedactionPattern::Email),
+        RedactionMark::area(1, Rect::new(50.0, 700.0, 300.0, 750.0)),
+    ];
+
+    // Draw black boxes labelled "REDACTED" in place of the removed content
+    let appearance = RedactionAppearance {
+        overlay_text: Some("REDACTED".to_string()),
+        ..Default::default()
+    };
+
+    // Permanently remove the marked content
+    let report = pdf.redact(&marks, &appearance)?;
+
+    // Print what was removed on which page
+    for item in &report.items {
+        println!("Page {}: {:?} at {:?}", item.page, item.content, item.rect);
+    }
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_redact.pdf")?;
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use asposepdf::{Document, RenderOptions};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Render the first page at 96 DPI without annotations
+    let image = pdf.render_page(1, &RenderOptions::dpi(96.0).with_annotations(false))?;
+    println!(
+        "Rendered {}x{} pixels, top-left pixel: {:?}",
+        image.width,
+        image.height,
+        image.pixel(0, 0)
+    );
+
+    Ok(())
+}
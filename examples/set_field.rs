@@ -0,0 +1,15 @@
+use asposepdf::Document;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with AcroForm
+    let pdf = Document::open("sample_form.pdf")?;
+
+    // Set the values of a text field and a check box
+    pdf.set_field("name", "John Doe")?;
+    pdf.set_field("agree", true)?;
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_set_field.pdf")?;
+
+    Ok(())
+}
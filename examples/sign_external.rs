@@ -0,0 +1,43 @@
+use asposepdf::{DigestAlgorithm, Document, PdfError, SignatureFormat, SignatureOptions, Signer};
+
+// A signer whose private key stays in a hardware security module
+struct HsmSigner {
+    certificate_chain: Vec<u8>,
+}
+
+impl Signer for HsmSigner {
+    fn sign(
+        &self,
+        digest: &[u8],
+        algorithm: DigestAlgorithm,
+        format: SignatureFormat,
+    ) -> Result<Vec<u8>, PdfError> {
+        // Build the signed attributes for the digest, sign them in the HSM
+        // and return the DER-encoded CMS SignedData
+        println!(
+            "signing {} byte {:?} digest as {} with a chain of {} bytes",
+            digest.len(),
+            algorithm,
+            format.sub_filter(),
+            self.certificate_chain.len()
+        );
+        Err(PdfError::CoreExceptionError(
+            "connect your HSM here".to_string(),
+        ))
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    let signer = HsmSigner {
+        certificate_chain: std::fs::read("signer_chain.pem")?,
+    };
+
+    // Sign the PDF-document with a PAdES baseline signature and save it as "sample_signed.pdf"
+    let options = SignatureOptions::pades().with_reason("Approved");
+    pdf.sign_external(&signer, &options, "sample_signed.pdf")?;
+
+    Ok(())
+}
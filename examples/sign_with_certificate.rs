@@ -0,0 +1,21 @@
+use asposepdf::{DigestAlgorithm, Document, Rect, SignatureOptions, SigningKey};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Read the certificate chain and the encrypted private key from PEM files
+    let key = SigningKey::from_files("signer_chain.pem", "signer_key.pem")?.with_password("secret");
+
+    // Create a visible PAdES baseline signature with SHA-384 on page 1
+    let options = SignatureOptions::pades()
+        .visible(1, Rect::new(100.0, 100.0, 300.0, 160.0))
+        .with_reason("Approved")
+        .with_location("Berlin")
+        .with_digest(DigestAlgorithm::Sha384);
+
+    // Sign the PDF-document and save it as "sample_signed.pdf"
+    pdf.sign_with_certificate(&key, &options, "sample_signed.pdf")?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use asposepdf::{Document, TrustStore};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document named "sample_with_sign.pdf"
+    let pdf = Document::open("sample_with_sign.pdf")?;
+
+    // Trust the root certificate from "root_ca.pem"
+    let trust_store = TrustStore::new().with_certificate(&std::fs::read("root_ca.pem")?);
+
+    // List and verify the signatures
+    for signature in pdf.signatures()? {
+        let signer = signature.signer().map_or("unknown", |c| c.subject.as_str());
+        println!("{}: signed by {}", signature.name, signer);
+
+        let result = pdf.verify_signature(&signature.name, &trust_store)?;
+        if result.is_valid() && result.covers_whole_document {
+            println!("  valid");
+        } else {
+            for error in &result.errors {
+                println!("  {}", error);
+            }
+        }
+    }
+
+    Ok(())
+}
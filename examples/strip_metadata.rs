@@ -0,0 +1,14 @@
+use asposepdf::Document;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Remove all metadata from PDF-document
+    pdf.strip_metadata()?;
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_strip_metadata.pdf")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use asposepdf::{Document, StructureElement};
+
+fn print_element(element: &StructureElement, depth: usize) {
+    println!(
+        "{}{} {:?} pages {:?}",
+        "  ".repeat(depth),
+        element.role,
+        element.text.as_deref().unwrap_or_default(),
+        element.pages
+    );
+    for child in &element.children {
+        print_element(child, depth + 1);
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a tagged PDF-document with filename
+    let pdf = Document::open("sample_tagged.pdf")?;
+
+    // Print the structure tree
+    let tree = pdf.structure_tree()?;
+    for element in &tree {
+        print_element(element, 0);
+    }
+
+    // Describe the figures without alternate text
+    for element in tree.iter().flat_map(|element| element.descendants()) {
+        if element.role == "Figure" && element.alt_text.is_none() {
+            pdf.set_structure_alt_text(&element.id, "Figure")?;
+        }
+    }
+
+    // Save the previously opened PDF-document with new filename
+    pdf.save_as("sample_tagged_alt.pdf")?;
+
+    Ok(())
+}
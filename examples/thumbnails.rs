@@ -0,0 +1,14 @@
+use asposepdf::{Document, ImageFormat};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document with filename
+    let pdf = Document::open("sample.pdf")?;
+
+    // Render a thumbnail of at most 160x160 pixels for every page
+    let thumbnails = pdf.thumbnails(160, 160, ImageFormat::Png)?;
+    for (i, thumbnail) in thumbnails.iter().enumerate() {
+        std::fs::write(format!("sample_thumbnail{}.png", i + 1), thumbnail)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use asposepdf::{DiffOptions, Document};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Open a PDF-document and optimize a copy of it
+    let original = Document::open("sample.pdf")?;
+    let optimized = Document::open_bytes(&original.bytes()?)?;
+    optimized.optimize_resource()?;
+
+    // Check that the optimization did not visibly change any page
+    let options = DiffOptions {
+        max_score: 0.001,
+        ..Default::default()
+    };
+    let diff = Document::visual_diff(&original, &optimized, &options)?;
+    for page in diff.changed_pages() {
+        println!("Page {} changed: {:.4}", page.page, page.score);
+    }
+    assert!(diff.is_match(), "optimization changed the document");
+
+    Ok(())
+}
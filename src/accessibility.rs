@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+/// AccessibilityCheck is the kind of a PDF/UA requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum AccessibilityCheck {
+    /// The PDF-document or some content is not tagged.
+    #[serde(rename = "tags")]
+    MissingTags,
+    /// A figure has no alternate text.
+    #[serde(rename = "alttext")]
+    MissingAltText,
+    /// The natural language of the PDF-document is not set.
+    #[serde(rename = "language")]
+    MissingLanguage,
+    /// The structure order does not match the logical reading order.
+    #[serde(rename = "readingorder")]
+    ReadingOrder,
+    /// The title is missing or the viewer is not set to display it instead of the file name.
+    #[serde(rename = "title")]
+    TitleDisplay,
+    /// Any other requirement.
+    #[serde(other)]
+    Other,
+}
+
+/// AccessibilityIssue is a failed PDF/UA requirement.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AccessibilityIssue {
+    #[serde(rename = "check")]
+    pub check: AccessibilityCheck,
+
+    /// The Matterhorn Protocol checkpoint (e.g. `"13-004"`).
+    #[serde(rename = "checkpoint")]
+    pub checkpoint: String,
+
+    #[serde(rename = "description")]
+    pub description: String,
+
+    /// The offending object (e.g. `"12 0 obj"`), `None` for document-level requirements.
+    #[serde(rename = "object", default)]
+    pub object: Option<String>,
+
+    /// The page number (1-based), `None` if the object is not on a page.
+    #[serde(rename = "page", default)]
+    pub page: Option<i32>,
+}
+
+/// AccessibilityReport lists the issues found by `Document::validate_pdfua`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct AccessibilityReport {
+    #[serde(rename = "issues", default)]
+    pub issues: Vec<AccessibilityIssue>,
+}
+
+impl AccessibilityReport {
+    /// Return true if no requirement is violated.
+    pub fn is_compliant(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Return the issues of the specified kind.
+    pub fn issues_of(
+        &self,
+        check: AccessibilityCheck,
+    ) -> impl Iterator<Item = &AccessibilityIssue> {
+        self.issues.iter().filter(move |issue| issue.check == check)
+    }
+}
@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+
+use crate::metadata::{deserialize_lenient_date, PdfDate};
+use crate::rect::Rect;
+
+/// AnnotationKind is the type of an annotation with its type-specific properties.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum AnnotationKind {
+    /// Text note ("sticky note").
+    #[serde(rename = "text")]
+    Text {
+        /// The icon name (e.g. `"Comment"`, `"Note"`, `"Help"`), `None` for the viewer default.
+        #[serde(rename = "icon", default)]
+        icon: Option<String>,
+        /// Whether the note is initially displayed open.
+        #[serde(rename = "open", default)]
+        open: bool,
+    },
+    /// Text highlight.
+    #[serde(rename = "highlight")]
+    Highlight {
+        /// The highlighted quadrilaterals as 8 coordinates each (x1 y1 x2 y2 x3 y3 x4 y4),
+        /// empty to highlight the whole annotation rectangle.
+        #[serde(rename = "quadpoints", default)]
+        quad_points: Vec<f64>,
+    },
+    /// Link to a URI or to a page of the PDF-document.
+    #[serde(rename = "link")]
+    Link {
+        #[serde(rename = "uri", default)]
+        uri: Option<String>,
+        /// The target page number (1-based).
+        #[serde(rename = "page", default)]
+        page: Option<i32>,
+    },
+    /// Rubber stamp.
+    #[serde(rename = "stamp")]
+    Stamp {
+        /// The stamp name (e.g. `"Approved"`, `"Draft"`, `"Confidential"`).
+        #[serde(rename = "name", default)]
+        name: Option<String>,
+    },
+    /// Freehand ink drawing.
+    #[serde(rename = "ink")]
+    Ink {
+        /// The strokes, each a list of `[x, y]` points.
+        #[serde(rename = "inklist", default)]
+        ink_list: Vec<Vec<[f64; 2]>>,
+    },
+    /// Text displayed directly on the page.
+    #[serde(rename = "freetext")]
+    FreeText {
+        #[serde(rename = "fontsize", default)]
+        font_size: Option<f64>,
+    },
+    /// Any other annotation type, reported when reading only.
+    #[serde(other)]
+    Other,
+}
+
+/// Annotation is an annotation on a page of the PDF-document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// The index of the annotation on the page (0-based). Ignored when adding an annotation.
+    #[serde(rename = "index", default, skip_serializing)]
+    pub index: usize,
+
+    #[serde(rename = "kind")]
+    pub kind: AnnotationKind,
+
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+
+    #[serde(rename = "author", default)]
+    pub author: Option<String>,
+
+    #[serde(rename = "contents", default)]
+    pub contents: Option<String>,
+
+    /// The color (hexadecimal format "#RRGGBB").
+    #[serde(rename = "color", default)]
+    pub color: Option<String>,
+
+    #[serde(
+        rename = "creationdate",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub creation_date: Option<PdfDate>,
+
+    #[serde(
+        rename = "moddate",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub mod_date: Option<PdfDate>,
+}
+
+impl Annotation {
+    /// Create an annotation of the specified kind.
+    pub fn new(kind: AnnotationKind, rect: Rect) -> Self {
+        Annotation {
+            index: 0,
+            kind,
+            rect,
+            author: None,
+            contents: None,
+            color: None,
+            creation_date: None,
+            mod_date: None,
+        }
+    }
+
+    /// Create a text note with the specified contents.
+    pub fn text(rect: Rect, contents: &str) -> Self {
+        Self::new(
+            AnnotationKind::Text {
+                icon: None,
+                open: false,
+            },
+            rect,
+        )
+        .with_contents(contents)
+    }
+
+    /// Create a highlight of the annotation rectangle.
+    pub fn highlight(rect: Rect) -> Self {
+        Self::new(
+            AnnotationKind::Highlight {
+                quad_points: Vec::new(),
+            },
+            rect,
+        )
+    }
+
+    /// Create a link to a URI.
+    pub fn link_uri(rect: Rect, uri: &str) -> Self {
+        Self::new(
+            AnnotationKind::Link {
+                uri: Some(uri.to_string()),
+                page: None,
+            },
+            rect,
+        )
+    }
+
+    /// Create a link to a page of the PDF-document (1-based).
+    pub fn link_page(rect: Rect, page: i32) -> Self {
+        Self::new(
+            AnnotationKind::Link {
+                uri: None,
+                page: Some(page),
+            },
+            rect,
+        )
+    }
+
+    /// Create a rubber stamp with the specified name.
+    pub fn stamp(rect: Rect, name: &str) -> Self {
+        Self::new(
+            AnnotationKind::Stamp {
+                name: Some(name.to_string()),
+            },
+            rect,
+        )
+    }
+
+    /// Create a freehand ink drawing from the strokes.
+    pub fn ink(rect: Rect, ink_list: Vec<Vec<[f64; 2]>>) -> Self {
+        Self::new(AnnotationKind::Ink { ink_list }, rect)
+    }
+
+    /// Create a text displayed directly on the page.
+    pub fn free_text(rect: Rect, contents: &str, font_size: f64) -> Self {
+        Self::new(
+            AnnotationKind::FreeText {
+                font_size: Some(font_size),
+            },
+            rect,
+        )
+        .with_contents(contents)
+    }
+
+    /// Return the annotation with the specified author.
+    pub fn with_author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    /// Return the annotation with the specified contents.
+    pub fn with_contents(mut self, contents: &str) -> Self {
+        self.contents = Some(contents.to_string());
+        self
+    }
+
+    /// Return the annotation with the specified color (hexadecimal format "#RRGGBB").
+    pub fn with_color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+
+    /// Return the annotation with the specified creation date.
+    pub fn with_creation_date(mut self, creation_date: PdfDate) -> Self {
+        self.creation_date = Some(creation_date);
+        self
+    }
+}
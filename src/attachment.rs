@@ -0,0 +1,81 @@
+use serde::Deserialize;
+
+use crate::metadata::{deserialize_lenient_date, PdfDate};
+
+/// Attachment describes a file embedded in the PDF-document.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Attachment {
+    /// The name of the embedded file.
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "description", default)]
+    pub description: Option<String>,
+
+    /// The MIME type of the embedded file (e.g. `"text/xml"`).
+    #[serde(rename = "mimetype", default)]
+    pub mime_type: Option<String>,
+
+    /// The size of the embedded file in bytes.
+    #[serde(rename = "size")]
+    pub size: u64,
+
+    #[serde(
+        rename = "creationdate",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub creation_date: Option<PdfDate>,
+
+    #[serde(
+        rename = "moddate",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub mod_date: Option<PdfDate>,
+
+    /// The MD5 checksum of the embedded file (hexadecimal), if present.
+    #[serde(rename = "checksum", default)]
+    pub checksum: Option<String>,
+
+    /// The relationship of the embedded file to the PDF-document (PDF/A-3), if present.
+    #[serde(rename = "afrelationship", default)]
+    pub relationship: Option<AfRelationship>,
+}
+
+/// AfRelationship is the relationship of an associated file to the PDF-document (PDF 2.0, PDF/A-3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum AfRelationship {
+    /// The original source material of the content.
+    Source,
+    /// Information used to derive a visual presentation, such as a table or a graph.
+    Data,
+    /// An alternative representation of the content (e.g. structured invoice data).
+    Alternative,
+    /// A supplemental representation of the original source or data.
+    Supplement,
+    /// An encrypted payload document.
+    EncryptedPayload,
+    /// Data associated with an AcroForm.
+    FormData,
+    /// A schema definition for the associated object.
+    Schema,
+    /// The relationship is not known or cannot be described by the other values.
+    Unspecified,
+}
+
+impl AfRelationship {
+    /// Return the PDF name of the relationship (e.g. `"Alternative"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AfRelationship::Source => "Source",
+            AfRelationship::Data => "Data",
+            AfRelationship::Alternative => "Alternative",
+            AfRelationship::Supplement => "Supplement",
+            AfRelationship::EncryptedPayload => "EncryptedPayload",
+            AfRelationship::FormData => "FormData",
+            AfRelationship::Schema => "Schema",
+            AfRelationship::Unspecified => "Unspecified",
+        }
+    }
+}
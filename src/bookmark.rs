@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rect::Rect;
+
+/// Destination defines the view of the page displayed when a bookmark is activated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Destination {
+    /// Position (`left`, `top`) at the top-left corner of the window with the specified zoom.
+    /// `None` values leave the current value unchanged.
+    #[serde(rename = "xyz")]
+    Xyz {
+        #[serde(rename = "left", default)]
+        left: Option<f64>,
+        #[serde(rename = "top", default)]
+        top: Option<f64>,
+        #[serde(rename = "zoom", default)]
+        zoom: Option<f64>,
+    },
+    /// Fit the entire page in the window.
+    #[serde(rename = "fit")]
+    Fit,
+    /// Fit the width of the page in the window with `top` at the top edge.
+    #[serde(rename = "fith")]
+    FitH {
+        #[serde(rename = "top", default)]
+        top: Option<f64>,
+    },
+    /// Fit the height of the page in the window with `left` at the left edge.
+    #[serde(rename = "fitv")]
+    FitV {
+        #[serde(rename = "left", default)]
+        left: Option<f64>,
+    },
+    /// Fit the rectangle in the window.
+    #[serde(rename = "fitr")]
+    FitR {
+        #[serde(rename = "rect")]
+        rect: Rect,
+    },
+}
+
+/// BookmarkStyle defines how a bookmark is displayed in the outline.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BookmarkStyle {
+    #[serde(rename = "bold", default)]
+    pub bold: bool,
+
+    #[serde(rename = "italic", default)]
+    pub italic: bool,
+
+    /// The text color (hexadecimal format "#RRGGBB"), `None` for the viewer default.
+    #[serde(rename = "color", default)]
+    pub color: Option<String>,
+}
+
+/// Bookmark is a node of the PDF-document outline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    #[serde(rename = "title")]
+    pub title: String,
+
+    /// The target page number (1-based).
+    #[serde(rename = "page")]
+    pub page: i32,
+
+    /// The target view of the page, `None` to display the page with the viewer defaults.
+    #[serde(rename = "destination", default)]
+    pub destination: Option<Destination>,
+
+    #[serde(rename = "children", default)]
+    pub children: Vec<Bookmark>,
+
+    #[serde(rename = "style", default)]
+    pub style: BookmarkStyle,
+}
+
+impl Bookmark {
+    /// Create a bookmark pointing to the specified page (1-based).
+    pub fn new(title: &str, page: i32) -> Self {
+        Bookmark {
+            title: title.to_string(),
+            page,
+            destination: None,
+            children: Vec::new(),
+            style: BookmarkStyle::default(),
+        }
+    }
+
+    /// Return the bookmark with the specified destination.
+    pub fn with_destination(mut self, destination: Destination) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+
+    /// Return the bookmark with the specified style.
+    pub fn with_style(mut self, style: BookmarkStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Return the bookmark with the specified child appended.
+    pub fn with_child(mut self, child: Bookmark) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+// Returns the children list of the node at `path` (0-based indices from the top level).
+// An empty path refers to the top level of the outline.
+#[cfg(feature = "core-preview")]
+pub(crate) fn children_mut<'a>(
+    bookmarks: &'a mut Vec<Bookmark>,
+    path: &[usize],
+) -> Option<&'a mut Vec<Bookmark>> {
+    match path.split_first() {
+        None => Some(bookmarks),
+        Some((index, rest)) => children_mut(&mut bookmarks.get_mut(*index)?.children, rest),
+    }
+}
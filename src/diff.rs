@@ -0,0 +1,153 @@
+use crate::render::RgbaImage;
+
+/// DiffOptions defines how `Document::visual_diff` compares the rendered pages of two PDF-documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffOptions {
+    /// The rendering resolution in dots per inch.
+    pub dpi: f64,
+
+    /// The largest per-channel difference (0-255) of a pixel still considered unchanged.
+    /// Absorbs anti-aliasing noise.
+    pub tolerance: u8,
+
+    /// The largest score of a page still considered matching (`0.0` to `1.0`).
+    pub max_score: f64,
+
+    /// Whether to produce a diff image for each changed page.
+    pub diff_images: bool,
+
+    /// The color of changed pixels in diff images (`[r, g, b, a]`).
+    pub highlight_color: [u8; 4],
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions {
+            dpi: 72.0,
+            tolerance: 16,
+            max_score: 0.0,
+            diff_images: false,
+            highlight_color: [255, 0, 0, 255],
+        }
+    }
+}
+
+/// PageDiff is the result of comparing a page of two PDF-documents.
+#[derive(Debug, Clone)]
+pub struct PageDiff {
+    /// The page number (1-based).
+    pub page: i32,
+
+    /// The fraction of changed pixels (`0.0` to `1.0`); `1.0` for a page missing in one PDF-document.
+    pub score: f64,
+
+    /// The number of changed pixels; all pixels of the existing page for a page missing in one PDF-document.
+    pub changed_pixels: u64,
+
+    /// The bounding box `(left, top, right, bottom)` of the changed pixels, `None` if no pixel changed.
+    pub changed_area: Option<(u32, u32, u32, u32)>,
+
+    /// The first page faded, with changed pixels in the highlight color.
+    /// `None` if diff images are disabled or no pixel changed.
+    pub diff_image: Option<RgbaImage>,
+}
+
+/// VisualDiff is the result of `Document::visual_diff`.
+#[derive(Debug, Clone)]
+pub struct VisualDiff {
+    /// The page counts of the first and the second PDF-document.
+    pub page_counts: (i32, i32),
+
+    pub pages: Vec<PageDiff>,
+
+    /// The largest score of a page still considered matching.
+    pub max_score: f64,
+}
+
+impl VisualDiff {
+    /// Return true if the page counts are equal and no page score exceeds `max_score`.
+    pub fn is_match(&self) -> bool {
+        self.page_counts.0 == self.page_counts.1
+            && self.pages.iter().all(|page| page.score <= self.max_score)
+    }
+
+    /// Return the pages whose score exceeds `max_score`.
+    pub fn changed_pages(&self) -> impl Iterator<Item = &PageDiff> {
+        self.pages
+            .iter()
+            .filter(move |page| page.score > self.max_score)
+    }
+}
+
+// Compares two rendered pages pixel by pixel.
+// Pixels outside one of the images, when their sizes differ, count as changed.
+#[cfg(feature = "core-preview")]
+pub(crate) fn compare_images(
+    page: i32,
+    a: &RgbaImage,
+    b: &RgbaImage,
+    options: &DiffOptions,
+) -> PageDiff {
+    let width = a.width.max(b.width);
+    let height = a.height.max(b.height);
+    let pixel = |image: &RgbaImage, x: u32, y: u32| {
+        (x < image.width && y < image.height).then(|| image.pixel(x, y))
+    };
+
+    let mut changed_pixels = 0u64;
+    let mut changed_area: Option<(u32, u32, u32, u32)> = None;
+    let mut diff_data = Vec::new();
+    if options.diff_images {
+        diff_data.reserve(width as usize * height as usize * 4);
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            let (pa, pb) = (pixel(a, x, y), pixel(b, x, y));
+            let changed = match (pa, pb) {
+                (Some(pa), Some(pb)) => pa
+                    .iter()
+                    .zip(pb.iter())
+                    .any(|(ca, cb)| ca.abs_diff(*cb) > options.tolerance),
+                _ => true,
+            };
+            if changed {
+                changed_pixels += 1;
+                changed_area = Some(match changed_area {
+                    None => (x, y, x + 1, y + 1),
+                    Some((l, t, r, b)) => (l.min(x), t.min(y), r.max(x + 1), b.max(y + 1)),
+                });
+            }
+            if options.diff_images {
+                if changed {
+                    diff_data.extend_from_slice(&options.highlight_color);
+                } else {
+                    // Fade the unchanged content to make the changes stand out
+                    let [r, g, bl, _] = pa.unwrap_or([255, 255, 255, 255]);
+                    let gray = (r as u32 * 30 + g as u32 * 59 + bl as u32 * 11) / 100;
+                    let faded = (255 - (255 - gray) / 4) as u8;
+                    diff_data.extend_from_slice(&[faded, faded, faded, 255]);
+                }
+            }
+        }
+    }
+
+    let total = width as u64 * height as u64;
+    let diff_image = (options.diff_images && changed_pixels > 0).then(|| RgbaImage {
+        width,
+        height,
+        stride: width as usize * 4,
+        data: diff_data,
+    });
+    PageDiff {
+        page,
+        score: if total == 0 {
+            0.0
+        } else {
+            changed_pixels as f64 / total as f64
+        },
+        changed_pixels,
+        changed_area,
+        diff_image,
+    }
+}
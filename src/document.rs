@@ -1,12 +1,69 @@
 #![allow(unsafe_code)]
 use serde_json;
+#[cfg(feature = "core-preview")]
+use std::collections::HashMap;
 use std::ffi::{c_char, c_int, c_uchar, c_void, CStr, CString};
 
+#[cfg(feature = "core-preview")]
+use crate::accessibility::AccessibilityReport;
+#[cfg(feature = "core-preview")]
+use crate::annotation::Annotation;
+#[cfg(feature = "core-preview")]
+use crate::attachment::{AfRelationship, Attachment};
+#[cfg(feature = "core-preview")]
+use crate::bookmark::{children_mut, Bookmark};
+#[cfg(feature = "core-preview")]
+use crate::diff::{compare_images, DiffOptions, VisualDiff};
+#[cfg(feature = "core-preview")]
+use crate::einvoice::{
+    check_cii_invoice, EInvoice, FacturXProfile, FACTURX_NAMESPACE, INVOICE_FILE_NAMES,
+    INVOICE_NAMESPACES,
+};
 use crate::enums::{CryptoAlgorithm, PageSize, Rotation};
+#[cfg(feature = "core-preview")]
+use crate::enums::{ImageEncoding, ImageFormat, PdfAFormat};
 use crate::errors::PdfError;
 use crate::extern_c::*;
+#[cfg(feature = "core-preview")]
+use crate::flatten::FlattenOptions;
+#[cfg(feature = "core-preview")]
+use crate::form::{
+    field_values_from, validate_field_value, FieldDefinition, FieldValue, FormField, ImportReport,
+    InvalidValue,
+};
+#[cfg(feature = "core-preview")]
+use crate::images::ImageInfo;
+#[cfg(feature = "core-preview")]
+use crate::merge::{field_renames, FieldRename, MergeOptions};
+#[cfg(feature = "core-preview")]
+use crate::metadata::Metadata;
+#[cfg(feature = "core-preview")]
+use crate::pdfa::{PdfAConversionReport, ValidationReport};
 use crate::permissions::Permissions;
 use crate::product_info::ProductInfo;
+#[cfg(feature = "core-preview")]
+use crate::rect::Rect;
+#[cfg(feature = "core-preview")]
+use crate::redaction::{RedactionAppearance, RedactionMark, RedactionReport};
+#[cfg(feature = "core-preview")]
+use crate::render::{RenderOptions, RgbaImage};
+#[cfg(feature = "core-preview")]
+use crate::signature::{
+    DigestAlgorithm, SignatureFormat, SignatureInfo, SignatureOptions, Signer, SigningKey,
+    TrustStore, VerificationResult,
+};
+#[cfg(feature = "core-preview")]
+use crate::structure::StructureElement;
+#[cfg(feature = "core-preview")]
+use crate::table::Table;
+#[cfg(feature = "core-preview")]
+use crate::text_diff::{TextChange, TextDiff};
+#[cfg(feature = "core-preview")]
+use crate::tiles::{extension, Tile, TileOptions, TilePyramid};
+#[cfg(feature = "core-preview")]
+use crate::utils::parse_page_range;
+#[cfg(feature = "core-preview")]
+use crate::xfa::XfaConversionReport;
 
 use crate::debug_println;
 use crate::generate_fn;
@@ -93,6 +150,31 @@ impl Document {
         }
     }
 
+    /// Open a PDF-document from memory.
+    ///
+    /// # Arguments
+    /// * `data` - The content of the PDF-document.
+    ///
+    /// # Returns
+    /// Returns `Ok(Self)` with the opened PDF-document instance, or `Err(PdfError)` if opening fails.
+    #[cfg(feature = "core-preview")]
+    pub fn open_bytes(data: &[u8]) -> Result<Self, PdfError> {
+        debug_println!("call Document::open_bytes(), size: {}", data.len());
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let doctmp = Document {
+            pdfdocumentclass: unsafe {
+                PDFDocument_Open_Memory(data.as_ptr(), data.len() as c_int, error.as_mut_ptr())
+            },
+        };
+        let error_str = Self::get_error(&mut error);
+        if doctmp.pdfdocumentclass.is_null() {
+            debug_println!("error Document::open_bytes(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        } else {
+            Ok(doctmp)
+        }
+    }
+
     /// Create a new PDF-document by merging the provided PDF-documents.
     ///
     /// # Arguments
@@ -133,6 +215,158 @@ impl Document {
         Ok(merged)
     }
 
+    /// Create a new PDF-document by merging the provided PDF-documents, renaming form fields
+    /// so that fields with the same name in different source PDF-documents keep independent values.
+    ///
+    /// A new name already used by another field gets a numeric suffix (e.g. `doc1_name_2`).
+    /// The source PDF-documents are not changed.
+    ///
+    /// # Arguments
+    /// * `documents` - A slice of references to PDF-documents [`Document`] to merge.
+    /// * `options` - The field renaming options [`MergeOptions`].
+    ///
+    /// # Returns
+    /// Returns `Ok((Self, Vec<FieldRename>))` with a new PDF-document instance and the renamed fields,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn merge_documents_with_options(
+        documents: &[&Document],
+        options: &MergeOptions,
+    ) -> Result<(Self, Vec<FieldRename>), PdfError> {
+        debug_println!(
+            "call Document::merge_documents_with_options(), total docs: {}",
+            documents.len()
+        );
+
+        if documents.is_empty() {
+            return Err(PdfError::CoreExceptionError(
+                "merge_documents_with_options(): no documents to merge".to_string(),
+            ));
+        }
+        if !options.labels.is_empty() && options.labels.len() != documents.len() {
+            return Err(PdfError::CoreExceptionError(format!(
+                "merge_documents_with_options(): {} labels for {} documents",
+                options.labels.len(),
+                documents.len()
+            )));
+        }
+
+        let mut field_names: Vec<Vec<String>> = Vec::with_capacity(documents.len());
+        for (i, doc) in documents.iter().enumerate() {
+            if doc.pdfdocumentclass.is_null() {
+                return Err(PdfError::CoreExceptionError(format!(
+                    "merge_documents_with_options(): document at index {} is null",
+                    i
+                )));
+            }
+            field_names.push(doc.form_fields()?.into_iter().map(|f| f.name).collect());
+        }
+
+        let merged = Self::new()?;
+        let renames = field_renames(&field_names, options);
+
+        for (i, doc) in documents.iter().enumerate() {
+            if let Err(e) = merged._append_renamed(doc, &renames[i]) {
+                return Err(PdfError::CoreExceptionError(format!(
+                    "merge_documents_with_options(): failed to append document at index {}: {}",
+                    i, e
+                )));
+            }
+        }
+
+        Ok((merged, renames.into_iter().flatten().collect()))
+    }
+
+    // Helper function used by `merge_documents_with_options` and `append_with_options`.
+    // Appends a copy of the other PDF-document with its fields renamed, keeping it unchanged.
+    #[cfg(feature = "core-preview")]
+    fn _append_renamed(&self, other: &Document, renames: &[FieldRename]) -> Result<(), PdfError> {
+        if renames.is_empty() {
+            return self.append(other);
+        }
+        let copy = Self::open_bytes(&other.bytes()?)?;
+        for rename in renames {
+            copy.rename_field(&rename.old_name, &rename.new_name)?;
+        }
+        self.append(&copy)
+    }
+
+    /// Compare the rendered pages of two PDF-documents pixel by pixel.
+    ///
+    /// Corresponding pages are rendered at the same resolution; a page missing in one
+    /// PDF-document has the score `1.0`, with all pixels of the existing page changed.
+    ///
+    /// # Arguments
+    /// * `a` - The first PDF-document [`Document`].
+    /// * `b` - The second PDF-document [`Document`].
+    /// * `options` - The comparison options [`DiffOptions`].
+    ///
+    /// # Returns
+    /// Returns `Ok(VisualDiff)` with per-page scores, changed areas and optional diff images,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn visual_diff(
+        a: &Document,
+        b: &Document,
+        options: &DiffOptions,
+    ) -> Result<VisualDiff, PdfError> {
+        debug_println!("call Document::visual_diff({options:?})");
+        let page_counts = (a.page_count()?, b.page_count()?);
+        let render_options = RenderOptions::dpi(options.dpi);
+
+        let mut pages = Vec::new();
+        for num in 1..=page_counts.0.max(page_counts.1) {
+            // A missing page compares as an empty image, so all pixels of the other page are changed
+            let render = |doc: &Document, page_count: i32| {
+                if num > page_count {
+                    Ok(RgbaImage {
+                        width: 0,
+                        height: 0,
+                        stride: 0,
+                        data: Vec::new(),
+                    })
+                } else {
+                    doc.render_page(num, &render_options)
+                }
+            };
+            let image_a = render(a, page_counts.0)?;
+            let image_b = render(b, page_counts.1)?;
+            pages.push(compare_images(num, &image_a, &image_b, options));
+        }
+
+        Ok(VisualDiff {
+            page_counts,
+            pages,
+            max_score: options.max_score,
+        })
+    }
+
+    /// Compare the texts of two PDF-documents page by page.
+    ///
+    /// # Arguments
+    /// * `a` - The first (original) PDF-document [`Document`].
+    /// * `b` - The second (revised) PDF-document [`Document`].
+    ///
+    /// # Returns
+    /// Returns `Ok(TextDiff)` with insertions, deletions and modifications, each with page and position,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn compare_text(a: &Document, b: &Document) -> Result<TextDiff, PdfError> {
+        debug_println!("call Document::compare_text()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            PDFDocument_CompareText(a.pdfdocumentclass, b.pdfdocumentclass, error.as_mut_ptr())
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::compare_text(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
     // Helper function used by `split` and `split_document`.
     // Splits the source document into multiple documents based on the page range string.
     fn _split_document(document: &Document, page_range: &str) -> Result<Vec<Self>, PdfError> {
@@ -349,6 +583,47 @@ impl Document {
         }
     }
 
+    /// Append another PDF-document, renaming form fields so that fields with the same name
+    /// in both PDF-documents keep independent values.
+    ///
+    /// The fields of this PDF-document are renamed as those of the first source PDF-document of
+    /// `merge_documents_with_options`, the fields of `other` as those of the second one.
+    /// The other PDF-document is not changed.
+    ///
+    /// # Arguments
+    /// * `other` - A reference to another PDF-document [`Document`] to append.
+    /// * `options` - The field renaming options [`MergeOptions`], with no or two labels.
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<FieldRename>)` with the renamed fields, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn append_with_options(
+        &self,
+        other: &Document,
+        options: &MergeOptions,
+    ) -> Result<Vec<FieldRename>, PdfError> {
+        debug_println!("call Document::append_with_options({options:?})");
+        if !options.labels.is_empty() && options.labels.len() != 2 {
+            return Err(PdfError::CoreExceptionError(format!(
+                "append_with_options(): {} labels for 2 documents",
+                options.labels.len()
+            )));
+        }
+
+        let field_names = [self, other]
+            .iter()
+            .map(|doc| Ok(doc.form_fields()?.into_iter().map(|f| f.name).collect()))
+            .collect::<Result<Vec<Vec<String>>, PdfError>>()?;
+        let renames = field_renames(&field_names, options);
+
+        for rename in &renames[0] {
+            self.rename_field(&rename.old_name, &rename.new_name)?;
+        }
+        self._append_renamed(other, &renames[1])?;
+
+        Ok(renames.into_iter().flatten().collect())
+    }
+
     /// Create multiple new PDF-documents by extracting pages from the current PDF-document.
     ///
     /// Each part of the `page_range` string (separated by `;`) defines the page range for a new PDF-document.
@@ -739,6 +1014,32 @@ impl Document {
         error_str
     }
 
+    // Converts raw string pointer returned from FFI into Rust String.
+    // The string is released on the C++ side after copying.
+    #[cfg(feature = "core-preview")]
+    fn get_string(char_ptr: *const c_char) -> String {
+        if char_ptr.is_null() {
+            return String::new();
+        }
+        let string = unsafe { CStr::from_ptr(char_ptr).to_string_lossy().into_owned() };
+        unsafe { c_free_string(char_ptr as *mut c_char) };
+        string
+    }
+
+    // Serializes a value passed to FFI as JSON string.
+    #[cfg(feature = "core-preview")]
+    fn to_json_c_string<T: serde::Serialize>(value: &T) -> Result<CString, PdfError> {
+        let json_str = serde_json::to_string(value)
+            .map_err(|e| PdfError::CoreExceptionError(e.to_string()))?;
+        CString::new(json_str).map_err(|e| PdfError::CoreExceptionError(e.to_string()))
+    }
+
+    // Deserializes a JSON string returned from FFI.
+    #[cfg(feature = "core-preview")]
+    fn from_json_str<T: serde::de::DeserializeOwned>(json_str: &str) -> Result<T, PdfError> {
+        serde_json::from_str(json_str).map_err(|e| PdfError::CoreExceptionError(e.to_string()))
+    }
+
     /// Add watermark to PDF-document.
     ///
     /// # Arguments
@@ -837,35 +1138,1929 @@ impl Document {
         let c_char_ptr_foreground_color = c_string_foreground_color.as_ptr();
         let _is_background: i32 = if is_background { 1 } else { 0 };
         let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
-        unsafe {
-            PDFDocument_Page_AddWatermark(
-                self.pdfdocumentclass,
-                num,
-                c_char_ptr_text as *const c_char,
-                c_char_ptr_font_name as *const c_char,
-                font_size,
-                c_char_ptr_foreground_color as *const c_char,
-                x_position,
-                y_position,
-                rotation,
-                _is_background,
-                opacity,
-                error.as_mut_ptr(),
-            )
+        unsafe {
+            PDFDocument_Page_AddWatermark(
+                self.pdfdocumentclass,
+                num,
+                c_char_ptr_text as *const c_char,
+                c_char_ptr_font_name as *const c_char,
+                font_size,
+                c_char_ptr_foreground_color as *const c_char,
+                x_position,
+                y_position,
+                rotation,
+                _is_background,
+                opacity,
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::page_add_watermark({text:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Redact areas of the PDF-document.
+    ///
+    /// Areas are marked by rectangle or by search pattern (SSNs, e-mail addresses, exact text or custom regex).
+    /// The underlying text, image pixels and vector content are permanently removed from the marked areas,
+    /// then filled boxes with optional overlay labels are drawn in their place.
+    ///
+    /// # Arguments
+    /// * `marks` - The areas to redact (see [`RedactionMark`]).
+    /// * `appearance` - The appearance of the filled boxes (see [`RedactionAppearance`]).
+    ///
+    /// # Returns
+    /// Returns `Ok(RedactionReport)` listing what was removed on which page, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn redact(
+        &self,
+        marks: &[RedactionMark],
+        appearance: &RedactionAppearance,
+    ) -> Result<RedactionReport, PdfError> {
+        debug_println!("call Document::redact(), total marks: {}", marks.len());
+        let c_string_marks = Self::to_json_c_string(&marks)?;
+        let c_string_appearance = Self::to_json_c_string(appearance)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            PDFDocument_Redact(
+                self.pdfdocumentclass,
+                c_string_marks.as_ptr(),
+                c_string_appearance.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::redact(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the PDF-document metadata (Title, Author, Subject, Keywords, Creator, Producer, CreationDate, ModDate).
+    ///
+    /// See also: `metadata.rs`
+    ///
+    /// # Returns
+    /// Returns `Ok(Metadata)` with the information dictionary fields, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn metadata(&self) -> Result<Metadata, PdfError> {
+        debug_println!("call Document::metadata()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_get_Metadata(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::metadata(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Set the PDF-document metadata.
+    ///
+    /// Fields set to `None` are removed from the information dictionary.
+    ///
+    /// # Arguments
+    /// * `metadata` - The new metadata [`Metadata`].
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_metadata(&self, metadata: &Metadata) -> Result<(), PdfError> {
+        debug_println!("call Document::set_metadata({metadata:?})");
+        let c_string_metadata = Self::to_json_c_string(metadata)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_set_Metadata(
+                self.pdfdocumentclass,
+                c_string_metadata.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::set_metadata(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the raw XMP metadata packet of the PDF-document.
+    ///
+    /// # Returns
+    /// Returns `Ok(String)` with the XMP packet (empty if the PDF-document has no XMP metadata),
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn xmp(&self) -> Result<String, PdfError> {
+        debug_println!("call Document::xmp()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe { PDFDocument_get_Xmp(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let xmp = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(xmp)
+        } else {
+            debug_println!("error Document::xmp(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Replace the raw XMP metadata packet of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `xmp` - The XMP packet.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_xmp(&self, xmp: &str) -> Result<(), PdfError> {
+        self._set_xmp(xmp)
+    }
+
+    /// Return a property of the XMP metadata.
+    ///
+    /// # Arguments
+    /// * `namespace_uri` - The namespace URI of the property (e.g. `"http://purl.org/dc/elements/1.1/"`).
+    /// * `name` - The property name without prefix.
+    ///
+    /// # Returns
+    /// Returns `Ok(Some(String))` with the property value, `Ok(None)` if the property is absent,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn xmp_property(
+        &self,
+        namespace_uri: &str,
+        name: &str,
+    ) -> Result<Option<String>, PdfError> {
+        debug_println!("call Document::xmp_property({namespace_uri:?}, {name:?})");
+        let c_string_namespace_uri = CString::new(namespace_uri).unwrap();
+        let c_string_name = CString::new(name).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            PDFDocument_get_XmpProperty(
+                self.pdfdocumentclass,
+                c_string_namespace_uri.as_ptr(),
+                c_string_name.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let value = if char_ptr.is_null() {
+            None
+        } else {
+            Some(Self::get_string(char_ptr))
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(value)
+        } else {
+            debug_println!(
+                "error Document::xmp_property({namespace_uri:?}, {name:?}): {error_str:?}"
+            );
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Set a property of the XMP metadata.
+    ///
+    /// Custom namespaces are registered in the XMP packet with the specified prefix.
+    ///
+    /// # Arguments
+    /// * `namespace_uri` - The namespace URI of the property.
+    /// * `prefix` - The namespace prefix (e.g. `"dc"`).
+    /// * `name` - The property name without prefix.
+    /// * `value` - The property value.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_xmp_property(
+        &self,
+        namespace_uri: &str,
+        prefix: &str,
+        name: &str,
+        value: &str,
+    ) -> Result<(), PdfError> {
+        debug_println!(
+            "call Document::set_xmp_property({namespace_uri:?}, {prefix:?}, {name:?}, {value:?})"
+        );
+        let c_string_namespace_uri = CString::new(namespace_uri).unwrap();
+        let c_string_prefix = CString::new(prefix).unwrap();
+        let c_string_name = CString::new(name).unwrap();
+        let c_string_value = CString::new(value).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_set_XmpProperty(
+                self.pdfdocumentclass,
+                c_string_namespace_uri.as_ptr(),
+                c_string_prefix.as_ptr(),
+                c_string_name.as_ptr(),
+                c_string_value.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!(
+                "error Document::set_xmp_property({namespace_uri:?}, {prefix:?}, {name:?}): {error_str:?}"
+            );
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the outline (bookmarks) of the PDF-document as a tree.
+    ///
+    /// See also: `bookmark.rs`
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<Bookmark>)` with the top-level bookmarks, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn bookmarks(&self) -> Result<Vec<Bookmark>, PdfError> {
+        debug_println!("call Document::bookmarks()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_get_Bookmarks(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::bookmarks(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Replace the outline (bookmarks) of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `bookmarks` - The top-level bookmarks [`Bookmark`] with their children.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_bookmarks(&self, bookmarks: &[Bookmark]) -> Result<(), PdfError> {
+        debug_println!(
+            "call Document::set_bookmarks(), total top-level bookmarks: {}",
+            bookmarks.len()
+        );
+        let c_string_bookmarks = Self::to_json_c_string(&bookmarks)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_set_Bookmarks(
+                self.pdfdocumentclass,
+                c_string_bookmarks.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::set_bookmarks(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Add a bookmark as the last child of the specified node.
+    ///
+    /// # Arguments
+    /// * `parent` - The path to the parent node (0-based indices from the top level), empty for the top level.
+    /// * `bookmark` - The bookmark [`Bookmark`] to add.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the path is invalid or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn add_bookmark(&self, parent: &[usize], bookmark: Bookmark) -> Result<(), PdfError> {
+        debug_println!(
+            "call Document::add_bookmark({parent:?}, {:?})",
+            bookmark.title
+        );
+        let mut bookmarks = self.bookmarks()?;
+        let children = children_mut(&mut bookmarks, parent).ok_or_else(|| {
+            PdfError::CoreExceptionError(format!(
+                "add_bookmark(): invalid bookmark path {:?}",
+                parent
+            ))
+        })?;
+        children.push(bookmark);
+        self.set_bookmarks(&bookmarks)
+    }
+
+    /// Replace the bookmark at the specified path, keeping its children.
+    ///
+    /// # Arguments
+    /// * `path` - The path to the node (0-based indices from the top level).
+    /// * `bookmark` - The new title, page, destination and style of the node.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the path is invalid or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn update_bookmark(&self, path: &[usize], bookmark: Bookmark) -> Result<(), PdfError> {
+        debug_println!(
+            "call Document::update_bookmark({path:?}, {:?})",
+            bookmark.title
+        );
+        let invalid_path = || {
+            PdfError::CoreExceptionError(format!(
+                "update_bookmark(): invalid bookmark path {:?}",
+                path
+            ))
+        };
+        let (index, parent) = path.split_last().ok_or_else(invalid_path)?;
+        let mut bookmarks = self.bookmarks()?;
+        let node = children_mut(&mut bookmarks, parent)
+            .and_then(|children| children.get_mut(*index))
+            .ok_or_else(invalid_path)?;
+        let children = std::mem::take(&mut node.children);
+        *node = Bookmark {
+            children,
+            ..bookmark
+        };
+        self.set_bookmarks(&bookmarks)
+    }
+
+    /// Delete the bookmark at the specified path together with its children.
+    ///
+    /// # Arguments
+    /// * `path` - The path to the node (0-based indices from the top level).
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the path is invalid or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn delete_bookmark(&self, path: &[usize]) -> Result<(), PdfError> {
+        debug_println!("call Document::delete_bookmark({path:?})");
+        let invalid_path = || {
+            PdfError::CoreExceptionError(format!(
+                "delete_bookmark(): invalid bookmark path {:?}",
+                path
+            ))
+        };
+        let (index, parent) = path.split_last().ok_or_else(invalid_path)?;
+        let mut bookmarks = self.bookmarks()?;
+        let children = children_mut(&mut bookmarks, parent).ok_or_else(invalid_path)?;
+        if *index >= children.len() {
+            return Err(invalid_path());
+        }
+        children.remove(*index);
+        self.set_bookmarks(&bookmarks)
+    }
+
+    /// Move the bookmark at the specified path to another position in the outline.
+    ///
+    /// # Arguments
+    /// * `path` - The path to the node to move (0-based indices from the top level).
+    /// * `new_parent` - The path to the new parent node, empty for the top level.
+    ///   The path refers to the outline after the node has been removed from its old position.
+    /// * `index` - The position among the children of the new parent (clamped to the number of children).
+    ///
+    /// # Errors
+    /// Returns `PdfError` if a path is invalid or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn move_bookmark(
+        &self,
+        path: &[usize],
+        new_parent: &[usize],
+        index: usize,
+    ) -> Result<(), PdfError> {
+        debug_println!("call Document::move_bookmark({path:?}, {new_parent:?}, {index})");
+        let invalid_path = |p: &[usize]| {
+            PdfError::CoreExceptionError(format!("move_bookmark(): invalid bookmark path {:?}", p))
+        };
+        let (old_index, old_parent) = path.split_last().ok_or_else(|| invalid_path(path))?;
+        let mut bookmarks = self.bookmarks()?;
+        let old_children =
+            children_mut(&mut bookmarks, old_parent).ok_or_else(|| invalid_path(path))?;
+        if *old_index >= old_children.len() {
+            return Err(invalid_path(path));
+        }
+        let node = old_children.remove(*old_index);
+        let new_children =
+            children_mut(&mut bookmarks, new_parent).ok_or_else(|| invalid_path(new_parent))?;
+        new_children.insert(index.min(new_children.len()), node);
+        self.set_bookmarks(&bookmarks)
+    }
+
+    /// Export the outline (bookmarks) of the PDF-document to a JSON-file.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the output JSON-file.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn export_bookmarks(&self, filename: &str) -> Result<(), PdfError> {
+        debug_println!("call Document::export_bookmarks({filename:?})");
+        let bookmarks = self.bookmarks()?;
+        let json_str = serde_json::to_string_pretty(&bookmarks)
+            .map_err(|e| PdfError::CoreExceptionError(e.to_string()))?;
+        std::fs::write(filename, json_str).map_err(PdfError::IoError)
+    }
+
+    /// Replace the outline (bookmarks) of the PDF-document with the tree from a JSON-file.
+    ///
+    /// The JSON-file has the format produced by [`Document::export_bookmarks`].
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the input JSON-file.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_bookmarks(&self, filename: &str) -> Result<(), PdfError> {
+        debug_println!("call Document::import_bookmarks({filename:?})");
+        let json_str = std::fs::read_to_string(filename).map_err(PdfError::IoError)?;
+        let bookmarks: Vec<Bookmark> = Self::from_json_str(&json_str)?;
+        self.set_bookmarks(&bookmarks)
+    }
+
+    /// Return the list of files embedded in the PDF-document.
+    ///
+    /// See also: `attachment.rs`
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<Attachment>)` with name, description, MIME type, size, dates and checksum of each file,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn attachments(&self) -> Result<Vec<Attachment>, PdfError> {
+        debug_println!("call Document::attachments()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_get_Attachments(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::attachments(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the contents of a file embedded in the PDF-document.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the embedded file.
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u8>)` containing the file contents, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn extract_attachment(&self, name: &str) -> Result<Vec<u8>, PdfError> {
+        debug_println!("call Document::extract_attachment({name:?})");
+        let c_string_name = CString::new(name).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let mut buf: *mut c_uchar = std::ptr::null_mut();
+        let mut size: i32 = 0;
+
+        unsafe {
+            PDFDocument_ExtractAttachment(
+                self.pdfdocumentclass,
+                c_string_name.as_ptr(),
+                &mut buf,
+                &mut size,
+                error.as_mut_ptr(),
+            );
+        }
+
+        let error_str = Self::get_error(&mut error);
+
+        // An embedded file may be empty, so only the error is checked
+        let bytes = if !buf.is_null() && size > 0 {
+            unsafe { std::slice::from_raw_parts(buf, size as usize).to_vec() }
+        } else {
+            Vec::new()
+        };
+        if !buf.is_null() {
+            unsafe { c_free_buffer(buf.cast()) };
+        }
+
+        if error_str.is_empty() {
+            Ok(bytes)
+        } else {
+            debug_println!("error Document::extract_attachment({name:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Embed a file in the PDF-document.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the embedded file.
+    /// * `data` - The file contents.
+    /// * `mime_type` - The MIME type of the file (e.g. `"text/xml"`).
+    /// * `description` - The description of the file.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn add_attachment(
+        &self,
+        name: &str,
+        data: &[u8],
+        mime_type: &str,
+        description: &str,
+    ) -> Result<(), PdfError> {
+        debug_println!(
+            "call Document::add_attachment({name:?}, {} bytes, {mime_type:?}, {description:?})",
+            data.len()
+        );
+        let c_string_name = CString::new(name).unwrap();
+        let c_string_mime_type = CString::new(mime_type).unwrap();
+        let c_string_description = CString::new(description).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_AddAttachment(
+                self.pdfdocumentclass,
+                c_string_name.as_ptr(),
+                data.as_ptr(),
+                data.len() as c_int,
+                c_string_mime_type.as_ptr(),
+                c_string_description.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::add_attachment({name:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the annotations on a page of the PDF-document.
+    ///
+    /// See also: `annotation.rs`
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<Annotation>)` with typed annotations in page order, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn annotations(&self, num: i32) -> Result<Vec<Annotation>, PdfError> {
+        debug_println!("call Document::annotations({num:?})");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            PDFDocument_Page_get_Annotations(self.pdfdocumentclass, num, error.as_mut_ptr())
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::annotations({num:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Add an annotation to a page of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `annotation` - The annotation [`Annotation`] to add.
+    ///
+    /// # Returns
+    /// Returns `Ok(usize)` with the index of the new annotation on the page (0-based), or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn add_annotation(&self, num: i32, annotation: &Annotation) -> Result<usize, PdfError> {
+        debug_println!(
+            "call Document::add_annotation({num:?}, {:?})",
+            annotation.kind
+        );
+        let c_string_annotation = Self::to_json_c_string(annotation)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let index: i32 = unsafe {
+            PDFDocument_Page_AddAnnotation(
+                self.pdfdocumentclass,
+                num,
+                c_string_annotation.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(index as usize)
+        } else {
+            debug_println!("error Document::add_annotation({num:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Modify an annotation on a page of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `index` - The index of the annotation on the page (0-based).
+    /// * `annotation` - The new properties of the annotation [`Annotation`].
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn update_annotation(
+        &self,
+        num: i32,
+        index: usize,
+        annotation: &Annotation,
+    ) -> Result<(), PdfError> {
+        debug_println!("call Document::update_annotation({num:?}, {index:?})");
+        let c_index = c_int::try_from(index).map_err(|_| {
+            PdfError::CoreExceptionError(format!(
+                "update_annotation(): invalid annotation index {}",
+                index
+            ))
+        })?;
+        let c_string_annotation = Self::to_json_c_string(annotation)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_Page_set_Annotation(
+                self.pdfdocumentclass,
+                num,
+                c_index,
+                c_string_annotation.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::update_annotation({num:?}, {index:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Delete an annotation on a page of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `index` - The index of the annotation on the page (0-based).
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn delete_annotation(&self, num: i32, index: usize) -> Result<(), PdfError> {
+        debug_println!("call Document::delete_annotation({num:?}, {index:?})");
+        let c_index = c_int::try_from(index).map_err(|_| {
+            PdfError::CoreExceptionError(format!(
+                "delete_annotation(): invalid annotation index {}",
+                index
+            ))
+        })?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_Page_DeleteAnnotation(
+                self.pdfdocumentclass,
+                num,
+                c_index,
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::delete_annotation({num:?}, {index:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the AcroForm fields of the PDF-document.
+    ///
+    /// See also: `form.rs`
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<FormField>)` with name, type, value, options, flags and widget rects of each field,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn form_fields(&self) -> Result<Vec<FormField>, PdfError> {
+        debug_println!("call Document::form_fields()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_get_FormFields(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::form_fields(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Set the value of an AcroForm field.
+    ///
+    /// # Arguments
+    /// * `name` - The fully qualified field name.
+    /// * `value` - The field value (text, `bool` for check boxes, or list of selected items).
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the field does not exist or the value is invalid for the field type.
+    #[cfg(feature = "core-preview")]
+    pub fn set_field(&self, name: &str, value: impl Into<FieldValue>) -> Result<(), PdfError> {
+        let mut values = HashMap::new();
+        values.insert(name.to_string(), value.into());
+        self.set_fields(&values)
+    }
+
+    /// Set the values of several AcroForm fields.
+    ///
+    /// # Arguments
+    /// * `values` - The field values keyed by fully qualified field name.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if a field does not exist or a value is invalid for the field type.
+    #[cfg(feature = "core-preview")]
+    pub fn set_fields(&self, values: &HashMap<String, FieldValue>) -> Result<(), PdfError> {
+        debug_println!(
+            "call Document::set_fields(), total fields: {}",
+            values.len()
+        );
+        let c_string_values = Self::to_json_c_string(values)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_set_FormFields(
+                self.pdfdocumentclass,
+                c_string_values.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::set_fields(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Fill AcroForm fields from any serializable value, such as a struct or a `HashMap`.
+    ///
+    /// Keys are fully qualified field names; nested structs and maps produce dotted names
+    /// (e.g. `applicant.name`), numbers are converted to text, and `None` values are skipped.
+    ///
+    /// # Arguments
+    /// * `data` - The field values.
+    /// * `flatten` - Whether to flatten the PDF-document after filling.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if a field does not exist, a value is invalid, or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn fill_form<T: serde::Serialize>(&self, data: &T, flatten: bool) -> Result<(), PdfError> {
+        debug_println!("call Document::fill_form({flatten:?})");
+        let values = field_values_from(data)?;
+        self.set_fields(&values)?;
+        if flatten {
+            self.flatten()?;
+        }
+        Ok(())
+    }
+
+    /// Add an AcroForm field to a page of the PDF-document.
+    ///
+    /// Text fields, check boxes, radio groups, combo boxes, list boxes, push buttons and signature fields are supported.
+    /// The new field is included in `export_fdf`, `export_xfdf` and `export_xml`.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `field` - The field definition [`FieldDefinition`].
+    ///
+    /// # Errors
+    /// Returns `PdfError` if a field with the same name exists or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn add_form_field(&self, num: i32, field: &FieldDefinition) -> Result<(), PdfError> {
+        debug_println!("call Document::add_form_field({num:?}, {:?})", field.name);
+        let c_string_field = Self::to_json_c_string(field)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_Page_AddFormField(
+                self.pdfdocumentclass,
+                num,
+                c_string_field.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!(
+                "error Document::add_form_field({num:?}, {:?}): {error_str:?}",
+                field.name
+            );
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    // Helper function used by `import_fdf`, `import_xfdf` and `import_xml`.
+    // Imports form data from a file with the specified FFI function.
+    #[cfg(feature = "core-preview")]
+    fn _import_form_data(
+        &self,
+        fn_name: &str,
+        filename: &str,
+        import_fn: unsafe extern "C" fn(
+            *const c_void,
+            *const c_char,
+            *mut *const c_char,
+        ) -> *const c_char,
+    ) -> Result<ImportReport, PdfError> {
+        debug_println!("call Document::{fn_name}({filename:?})");
+        let c_string_filename = CString::new(filename).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            import_fn(
+                self.pdfdocumentclass,
+                c_string_filename.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::{fn_name}({filename:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    // Helper function used by `import_fdf_bytes`, `import_xfdf_bytes` and `import_xml_bytes`.
+    // Imports form data from memory with the specified FFI function.
+    #[cfg(feature = "core-preview")]
+    fn _import_form_data_bytes(
+        &self,
+        fn_name: &str,
+        data: &[u8],
+        import_fn: unsafe extern "C" fn(
+            *const c_void,
+            *const u8,
+            c_int,
+            *mut *const c_char,
+        ) -> *const c_char,
+    ) -> Result<ImportReport, PdfError> {
+        debug_println!("call Document::{fn_name}(), {} bytes", data.len());
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            import_fn(
+                self.pdfdocumentclass,
+                data.as_ptr(),
+                data.len() as c_int,
+                error.as_mut_ptr(),
+            )
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::{fn_name}(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Import data from FDF-file into AcroForm fields of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the FDF-file.
+    ///
+    /// # Returns
+    /// Returns `Ok(ImportReport)` listing imported fields, unknown fields and values that failed type validation,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_fdf(&self, filename: &str) -> Result<ImportReport, PdfError> {
+        self._import_form_data("import_fdf", filename, PDFDocument_Import_Fdf)
+    }
+
+    /// Import data from XFDF-file into AcroForm fields of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the XFDF-file.
+    ///
+    /// # Returns
+    /// Returns `Ok(ImportReport)` listing imported fields, unknown fields and values that failed type validation,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_xfdf(&self, filename: &str) -> Result<ImportReport, PdfError> {
+        self._import_form_data("import_xfdf", filename, PDFDocument_Import_Xfdf)
+    }
+
+    /// Import data from XML-file into AcroForm fields of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the XML-file.
+    ///
+    /// # Returns
+    /// Returns `Ok(ImportReport)` listing imported fields, unknown fields and values that failed type validation,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_xml(&self, filename: &str) -> Result<ImportReport, PdfError> {
+        self._import_form_data("import_xml", filename, PDFDocument_Import_Xml)
+    }
+
+    /// Import FDF-data into AcroForm fields of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `data` - The contents of the FDF-file.
+    ///
+    /// # Returns
+    /// Returns `Ok(ImportReport)` listing imported fields, unknown fields and values that failed type validation,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_fdf_bytes(&self, data: &[u8]) -> Result<ImportReport, PdfError> {
+        self._import_form_data_bytes("import_fdf_bytes", data, PDFDocument_Import_Fdf_Memory)
+    }
+
+    /// Import XFDF-data into AcroForm fields of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `data` - The contents of the XFDF-file.
+    ///
+    /// # Returns
+    /// Returns `Ok(ImportReport)` listing imported fields, unknown fields and values that failed type validation,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_xfdf_bytes(&self, data: &[u8]) -> Result<ImportReport, PdfError> {
+        self._import_form_data_bytes("import_xfdf_bytes", data, PDFDocument_Import_Xfdf_Memory)
+    }
+
+    /// Import XML-data into AcroForm fields of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `data` - The contents of the XML-file.
+    ///
+    /// # Returns
+    /// Returns `Ok(ImportReport)` listing imported fields, unknown fields and values that failed type validation,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_xml_bytes(&self, data: &[u8]) -> Result<ImportReport, PdfError> {
+        self._import_form_data_bytes("import_xml_bytes", data, PDFDocument_Import_Xml_Memory)
+    }
+
+    /// Import data from JSON-file into AcroForm fields of the PDF-document.
+    ///
+    /// The JSON-file contains an object keyed by fully qualified field name, e.g. `{"name": "John", "agree": true}`.
+    ///
+    /// # Arguments
+    /// * `filename` - The path to the JSON-file.
+    ///
+    /// # Returns
+    /// Returns `Ok(ImportReport)` listing imported fields, unknown fields and values that failed type validation,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_json(&self, filename: &str) -> Result<ImportReport, PdfError> {
+        debug_println!("call Document::import_json({filename:?})");
+        let data = std::fs::read(filename).map_err(PdfError::IoError)?;
+        self.import_json_bytes(&data)
+    }
+
+    /// Import JSON-data into AcroForm fields of the PDF-document.
+    ///
+    /// The JSON-data contains an object keyed by fully qualified field name, e.g. `{"name": "John", "agree": true}`.
+    ///
+    /// # Arguments
+    /// * `data` - The contents of the JSON-file.
+    ///
+    /// # Returns
+    /// Returns `Ok(ImportReport)` listing imported fields, unknown fields and values that failed type validation,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn import_json_bytes(&self, data: &[u8]) -> Result<ImportReport, PdfError> {
+        debug_println!("call Document::import_json_bytes(), {} bytes", data.len());
+        let json_value: serde_json::Value = serde_json::from_slice(data)
+            .map_err(|e| PdfError::CoreExceptionError(e.to_string()))?;
+        let values = field_values_from(&json_value)?;
+        let fields = self.form_fields()?;
+
+        let mut report = ImportReport::default();
+        let mut valid_values = HashMap::new();
+        for (name, value) in values {
+            match fields.iter().find(|field| field.name == name) {
+                None => report.unknown_fields.push(name),
+                Some(field) => match validate_field_value(field, &value) {
+                    Ok(()) => {
+                        report.imported.push(name.clone());
+                        valid_values.insert(name, value);
+                    }
+                    Err(reason) => report.invalid_values.push(InvalidValue {
+                        field: name,
+                        value: value.to_string(),
+                        reason,
+                    }),
+                },
+            }
+        }
+        report.imported.sort();
+        report.unknown_fields.sort();
+        report.invalid_values.sort_by(|a, b| a.field.cmp(&b.field));
+
+        if !valid_values.is_empty() {
+            self.set_fields(&valid_values)?;
+        }
+        Ok(report)
+    }
+
+    /// Flatten the selected form fields and annotations of the PDF-document.
+    ///
+    /// Flattened content is merged into the page content and is no longer interactive.
+    /// The rest of the PDF-document remains fillable and editable.
+    ///
+    /// # Arguments
+    /// * `options` - The selection of content to flatten [`FlattenOptions`].
+    ///
+    /// # Errors
+    /// Returns `PdfError` if a field name or page number is invalid or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn flatten_with_options(&self, options: &FlattenOptions) -> Result<(), PdfError> {
+        debug_println!("call Document::flatten_with_options({options:?})");
+        let c_string_options = Self::to_json_c_string(options)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_FlattenWithOptions(
+                self.pdfdocumentclass,
+                c_string_options.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::flatten_with_options(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Check whether the PDF-document contains an XFA form.
+    ///
+    /// # Returns
+    /// * `Ok(bool)` - True if the PDF-document contains an XFA form.
+    /// * `Err(PdfError)` - If the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn has_xfa(&self) -> Result<bool, PdfError> {
+        debug_println!("call Document::has_xfa()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let xfa: i32 = unsafe { PDFDocument_has_Xfa(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(xfa != 0)
+        } else {
+            debug_println!("error Document::has_xfa(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the XFA XML packet (XDP) of the PDF-document.
+    ///
+    /// # Returns
+    /// Returns `Ok(Some(String))` with the XDP document, `Ok(None)` if the PDF-document has no XFA form,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn xfa_data(&self) -> Result<Option<String>, PdfError> {
+        debug_println!("call Document::xfa_data()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_get_XfaData(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let value = if char_ptr.is_null() {
+            None
+        } else {
+            Some(Self::get_string(char_ptr))
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(value)
+        } else {
+            debug_println!("error Document::xfa_data(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Convert the XFA form of the PDF-document to a static AcroForm.
+    ///
+    /// Dynamic XFA layout is resolved to fixed pages and XFA fields become AcroForm fields,
+    /// so that `form_fields`, `export_fdf` and `flatten` can be used afterwards.
+    /// The XFA packet is removed from the PDF-document.
+    ///
+    /// # Returns
+    /// Returns `Ok(XfaConversionReport)` with the converted and dropped fields,
+    /// or `Err(PdfError)` if the PDF-document has no XFA form or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn convert_xfa_to_acroform(&self) -> Result<XfaConversionReport, PdfError> {
+        debug_println!("call Document::convert_xfa_to_acroform()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_ConvertXfaToAcroForm(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::convert_xfa_to_acroform(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the images placed on a page of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<ImageInfo>)` with dimensions, bits per component, color space, filter,
+    /// effective DPI and placement rect of each image, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn images(&self, num: i32) -> Result<Vec<ImageInfo>, PdfError> {
+        debug_println!("call Document::images({num:?})");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_Page_get_Images(self.pdfdocumentclass, num, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::images({num:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Extract an image of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `id` - The image identifier from [`ImageInfo`].
+    /// * `encoding` - The encoding of the returned image [`ImageEncoding`].
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u8>)` with the encoded image, or `Err(PdfError)` if the image does not exist
+    /// or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn extract_image(&self, id: &str, encoding: ImageEncoding) -> Result<Vec<u8>, PdfError> {
+        debug_println!("call Document::extract_image({id:?}, {encoding:?})");
+        let c_string_id = CString::new(id).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let mut buf: *mut c_uchar = std::ptr::null_mut();
+        let mut size: i32 = 0;
+
+        unsafe {
+            PDFDocument_ExtractImage(
+                self.pdfdocumentclass,
+                c_string_id.as_ptr(),
+                encoding as c_int,
+                &mut buf,
+                &mut size,
+                error.as_mut_ptr(),
+            );
+        }
+
+        let error_str = Self::get_error(&mut error);
+
+        if error_str.is_empty() && !buf.is_null() && size > 0 {
+            let bytes = unsafe { std::slice::from_raw_parts(buf, size as usize).to_vec() };
+            unsafe { c_free_buffer(buf.cast()) };
+            Ok(bytes)
+        } else {
+            if !buf.is_null() {
+                unsafe { c_free_buffer(buf.cast()) };
+            }
+
+            debug_println!("error Document::extract_image({id:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Extract the tables detected on the selected pages of the PDF-document.
+    ///
+    /// See also: `Table::to_csv`, `Table::to_json`
+    ///
+    /// # Arguments
+    /// * `page_range` - A string defining the page ranges (e.g. "-2,4,6-8,10-").
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<Table>)` with the tables in page order, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn extract_tables(&self, page_range: &str) -> Result<Vec<Table>, PdfError> {
+        debug_println!("call Document::extract_tables({page_range:?})");
+        let c_string_page_range = CString::new(page_range).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            PDFDocument_ExtractTables(
+                self.pdfdocumentclass,
+                c_string_page_range.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::extract_tables({page_range:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Render a page of the PDF-document to an in-memory RGBA buffer.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `options` - The rendering options [`RenderOptions`].
+    ///
+    /// # Returns
+    /// Returns `Ok(RgbaImage)` with the rendered pixels, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn render_page(&self, num: i32, options: &RenderOptions) -> Result<RgbaImage, PdfError> {
+        debug_println!("call Document::render_page({num:?}, {options:?})");
+        let c_string_options = Self::to_json_c_string(options)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let mut buf: *mut c_uchar = std::ptr::null_mut();
+        let mut size: i32 = 0;
+        let mut width: i32 = 0;
+        let mut height: i32 = 0;
+        let mut stride: i32 = 0;
+
+        unsafe {
+            PDFDocument_Page_Render(
+                self.pdfdocumentclass,
+                num,
+                c_string_options.as_ptr(),
+                &mut buf,
+                &mut size,
+                &mut width,
+                &mut height,
+                &mut stride,
+                error.as_mut_ptr(),
+            );
+        }
+
+        let error_str = Self::get_error(&mut error);
+
+        if error_str.is_empty() && !buf.is_null() && size > 0 {
+            let data = unsafe { std::slice::from_raw_parts(buf, size as usize).to_vec() };
+            unsafe { c_free_buffer(buf.cast()) };
+            let image = RgbaImage {
+                width: u32::try_from(width).unwrap_or(0),
+                height: u32::try_from(height).unwrap_or(0),
+                stride: usize::try_from(stride).unwrap_or(0),
+                data,
+            };
+            if image.is_valid() {
+                Ok(image)
+            } else {
+                Err(PdfError::CoreExceptionError(format!(
+                    "render_page(): {} bytes with stride {} do not hold {}x{} pixels",
+                    size, stride, width, height
+                )))
+            }
+        } else {
+            if !buf.is_null() {
+                unsafe { c_free_buffer(buf.cast()) };
+            }
+
+            debug_println!("error Document::render_page({num:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Render a page of the PDF-document to an image of bounded size.
+    ///
+    /// The page is scaled, keeping its aspect ratio, to fit within `max_width` x `max_height` pixels.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `max_width` - The maximum width in pixels.
+    /// * `max_height` - The maximum height in pixels.
+    /// * `format` - The image format [`ImageFormat`].
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u8>)` with the encoded image, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn page_thumbnail(
+        &self,
+        num: i32,
+        max_width: u32,
+        max_height: u32,
+        format: ImageFormat,
+    ) -> Result<Vec<u8>, PdfError> {
+        debug_println!(
+            "call Document::page_thumbnail({num:?}, {max_width:?}, {max_height:?}, {format:?})"
+        );
+        let c_size = |value: u32| {
+            c_int::try_from(value).map_err(|_| {
+                PdfError::CoreExceptionError(format!("page_thumbnail(): invalid size {}", value))
+            })
+        };
+        let (c_max_width, c_max_height) = (c_size(max_width)?, c_size(max_height)?);
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let mut buf: *mut c_uchar = std::ptr::null_mut();
+        let mut size: i32 = 0;
+
+        unsafe {
+            PDFDocument_Page_Thumbnail(
+                self.pdfdocumentclass,
+                num,
+                c_max_width,
+                c_max_height,
+                format as c_int,
+                &mut buf,
+                &mut size,
+                error.as_mut_ptr(),
+            );
+        }
+
+        let error_str = Self::get_error(&mut error);
+
+        if error_str.is_empty() && !buf.is_null() && size > 0 {
+            let bytes = unsafe { std::slice::from_raw_parts(buf, size as usize).to_vec() };
+            unsafe { c_free_buffer(buf.cast()) };
+            Ok(bytes)
+        } else {
+            if !buf.is_null() {
+                unsafe { c_free_buffer(buf.cast()) };
+            }
+
+            debug_println!("error Document::page_thumbnail({num:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Render every page of the PDF-document to an image of bounded size, in parallel.
+    ///
+    /// See also: `page_thumbnail`, `thumbnails_range`
+    ///
+    /// # Arguments
+    /// * `max_width` - The maximum width in pixels.
+    /// * `max_height` - The maximum height in pixels.
+    /// * `format` - The image format [`ImageFormat`].
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<Vec<u8>>)` with the encoded images in page order, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn thumbnails(
+        &self,
+        max_width: u32,
+        max_height: u32,
+        format: ImageFormat,
+    ) -> Result<Vec<Vec<u8>>, PdfError> {
+        self.thumbnails_range("", max_width, max_height, format)
+    }
+
+    /// Render the selected pages of the PDF-document to images of bounded size, in parallel.
+    ///
+    /// Each worker thread renders its pages with its own PDF-document instance opened from the bytes of this one.
+    ///
+    /// # Arguments
+    /// * `page_range` - A string defining the page ranges (e.g. "-2,4,6-8,10-"), empty for all pages.
+    /// * `max_width` - The maximum width in pixels.
+    /// * `max_height` - The maximum height in pixels.
+    /// * `format` - The image format [`ImageFormat`].
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<Vec<u8>>)` with the encoded images in the order of `page_range`,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn thumbnails_range(
+        &self,
+        page_range: &str,
+        max_width: u32,
+        max_height: u32,
+        format: ImageFormat,
+    ) -> Result<Vec<Vec<u8>>, PdfError> {
+        debug_println!("call Document::thumbnails_range({page_range:?}, {max_width:?}, {max_height:?}, {format:?})");
+        let pages = parse_page_range(page_range, self.page_count()?)?;
+        let workers = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .min(pages.len());
+
+        if workers <= 1 {
+            return pages
+                .iter()
+                .map(|num| self.page_thumbnail(*num, max_width, max_height, format))
+                .collect();
+        }
+
+        // Document is not Send, so every worker opens its own instance from the same bytes
+        let bytes = self.bytes()?;
+        let mut thumbnails: Vec<Vec<u8>> = vec![Vec::new(); pages.len()];
+        std::thread::scope(|scope| -> Result<(), PdfError> {
+            let handles: Vec<_> = (0..workers)
+                .map(|worker| {
+                    let bytes = &bytes;
+                    let pages = &pages;
+                    scope.spawn(move || -> Result<Vec<(usize, Vec<u8>)>, PdfError> {
+                        let doc = Self::open_bytes(bytes)?;
+                        (worker..pages.len())
+                            .step_by(workers)
+                            .map(|i| {
+                                doc.page_thumbnail(pages[i], max_width, max_height, format)
+                                    .map(|thumbnail| (i, thumbnail))
+                            })
+                            .collect()
+                    })
+                })
+                .collect();
+            for handle in handles {
+                let results = handle.join().map_err(|_| {
+                    PdfError::CoreExceptionError(
+                        "thumbnails_range(): worker thread panicked".to_string(),
+                    )
+                })??;
+                for (i, thumbnail) in results {
+                    thumbnails[i] = thumbnail;
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(thumbnails)
+    }
+
+    /// Return the visible area (crop box) of a page of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    ///
+    /// # Returns
+    /// Returns `Ok(Rect)` in page coordinates, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn page_rect(&self, num: i32) -> Result<Rect, PdfError> {
+        debug_println!("call Document::page_rect({num:?})");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_Page_get_Rect(self.pdfdocumentclass, num, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::page_rect({num:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Render a region of a page of the PDF-document at the specified zoom.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `rect` - The region to render in page coordinates.
+    /// * `zoom` - The zoom, `1.0` for 1 pixel per point (72 DPI).
+    /// * `format` - The image format [`ImageFormat`].
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u8>)` with the encoded image, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn render_region(
+        &self,
+        num: i32,
+        rect: &Rect,
+        zoom: f64,
+        format: ImageFormat,
+    ) -> Result<Vec<u8>, PdfError> {
+        debug_println!("call Document::render_region({num:?}, {rect:?}, {zoom:?}, {format:?})");
+        if !zoom.is_finite() || zoom <= 0.0 {
+            return Err(PdfError::CoreExceptionError(format!(
+                "render_region(): invalid zoom {}",
+                zoom
+            )));
+        }
+        let c_string_rect = Self::to_json_c_string(rect)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let mut buf: *mut c_uchar = std::ptr::null_mut();
+        let mut size: i32 = 0;
+
+        unsafe {
+            PDFDocument_Page_RenderRegion(
+                self.pdfdocumentclass,
+                num,
+                c_string_rect.as_ptr(),
+                zoom,
+                format as c_int,
+                &mut buf,
+                &mut size,
+                error.as_mut_ptr(),
+            );
+        }
+
+        let error_str = Self::get_error(&mut error);
+
+        if error_str.is_empty() && !buf.is_null() && size > 0 {
+            let bytes = unsafe { std::slice::from_raw_parts(buf, size as usize).to_vec() };
+            unsafe { c_free_buffer(buf.cast()) };
+            Ok(bytes)
+        } else {
+            if !buf.is_null() {
+                unsafe { c_free_buffer(buf.cast()) };
+            }
+
+            debug_println!("error Document::render_region({num:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Render the deep-zoom tile pyramid of a page of the PDF-document.
+    ///
+    /// Tiles are passed to `callback` level by level, from the 1x1 pixel level to the most detailed one,
+    /// row by row within a level. An error returned by `callback` stops the rendering.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `options` - The tile options [`TileOptions`].
+    /// * `callback` - The function receiving each [`Tile`].
+    ///
+    /// # Returns
+    /// Returns `Ok(TilePyramid)` with the layout of the pyramid, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn page_tiles<F>(
+        &self,
+        num: i32,
+        options: &TileOptions,
+        mut callback: F,
+    ) -> Result<TilePyramid, PdfError>
+    where
+        F: FnMut(Tile) -> Result<(), PdfError>,
+    {
+        debug_println!("call Document::page_tiles({num:?}, {options:?})");
+        if options.tile_size == 0 || !options.zoom.is_finite() || options.zoom <= 0.0 {
+            return Err(PdfError::CoreExceptionError(format!(
+                "page_tiles(): invalid tile size {} or zoom {}",
+                options.tile_size, options.zoom
+            )));
+        }
+
+        let page_rect = self.page_rect(num)?;
+        let pyramid = TilePyramid {
+            width: (page_rect.width() * options.zoom).ceil().max(1.0) as u32,
+            height: (page_rect.height() * options.zoom).ceil().max(1.0) as u32,
+            tile_size: options.tile_size,
+            overlap: options.overlap,
+            format: options.format,
+        };
+
+        for level in 0..=pyramid.max_level() {
+            let (columns, rows) = pyramid.tile_count(level);
+            for row in 0..rows {
+                for column in 0..columns {
+                    let (region, zoom) =
+                        pyramid.tile_region(&page_rect, options.zoom, level, column, row);
+                    let data = self.render_region(num, &region, zoom, options.format)?;
+                    callback(Tile {
+                        level,
+                        column,
+                        row,
+                        data,
+                    })?;
+                }
+            }
+        }
+
+        Ok(pyramid)
+    }
+
+    /// Save the deep-zoom tile pyramid of a page of the PDF-document in the Deep Zoom Image (DZI) layout.
+    ///
+    /// Writes the descriptor `{dir}/{name}.dzi` and the tiles `{dir}/{name}_files/{level}/{column}_{row}.{ext}`.
+    ///
+    /// # Arguments
+    /// * `num` - The page number (1-based).
+    /// * `options` - The tile options [`TileOptions`].
+    /// * `dir` - The output directory, created if missing.
+    /// * `name` - The base name of the descriptor and the tile directory.
+    ///
+    /// # Returns
+    /// Returns `Ok(TilePyramid)` with the layout of the pyramid, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn save_page_tiles(
+        &self,
+        num: i32,
+        options: &TileOptions,
+        dir: &str,
+        name: &str,
+    ) -> Result<TilePyramid, PdfError> {
+        debug_println!("call Document::save_page_tiles({num:?}, {dir:?}, {name:?})");
+        let files_dir = std::path::Path::new(dir).join(format!("{name}_files"));
+        let extension = extension(options.format);
+
+        let pyramid = self.page_tiles(num, options, |tile| {
+            let level_dir = files_dir.join(tile.level.to_string());
+            std::fs::create_dir_all(&level_dir).map_err(PdfError::IoError)?;
+            std::fs::write(
+                level_dir.join(format!("{}_{}.{}", tile.column, tile.row, extension)),
+                tile.data,
+            )
+            .map_err(PdfError::IoError)
+        })?;
+
+        std::fs::write(
+            std::path::Path::new(dir).join(format!("{name}.dzi")),
+            pyramid.to_dzi(),
+        )
+        .map_err(PdfError::IoError)?;
+
+        Ok(pyramid)
+    }
+
+    /// Save a copy of the PDF-document with the changes of a text comparison highlighted.
+    ///
+    /// Call on the second (revised) PDF-document passed to `compare_text`. Insertions are highlighted in green
+    /// and modifications in yellow with the replaced text in the annotation contents. Deletions are marked with
+    /// red text notes. Deleted text has no position in the revised PDF-document, so the note is placed on the page
+    /// of the nearest preceding change (the first following change at the start, the original page number clamped
+    /// to the page count without such changes) at the position of the text in the first PDF-document.
+    /// The PDF-document itself is not changed.
+    ///
+    /// # Arguments
+    /// * `diff` - The result of `compare_text` [`TextDiff`].
+    /// * `filename` - The path to the output PDF-file.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn save_text_comparison(&self, diff: &TextDiff, filename: &str) -> Result<(), PdfError> {
+        debug_println!("call Document::save_text_comparison({filename:?})");
+        let comparison = Self::open_bytes(&self.bytes()?)?;
+        let page_count = comparison.page_count()?;
+        if page_count == 0 && !diff.is_empty() {
+            return Err(PdfError::CoreExceptionError(
+                "save_text_comparison(): the PDF-document has no pages to mark the changes on"
+                    .to_string(),
+            ));
+        }
+
+        // Revised page of the nearest preceding change, initially of the first following change.
+        let mut revised_page = diff
+            .changes
+            .iter()
+            .find_map(|change| change.after())
+            .map(|new| new.page);
+
+        for change in &diff.changes {
+            if let Some(new) = change.after() {
+                revised_page = Some(new.page);
+            }
+            let (page, annotation) = match change {
+                TextChange::Insert { new } => (
+                    new.page,
+                    Annotation::highlight(new.rect)
+                        .with_color("#00FF00")
+                        .with_contents(&format!("Inserted: {}", new.text)),
+                ),
+                TextChange::Modify { old, new } => (
+                    new.page,
+                    Annotation::highlight(new.rect)
+                        .with_color("#FFFF00")
+                        .with_contents(&format!("Replaced: {}", old.text)),
+                ),
+                TextChange::Delete { old } => (
+                    revised_page.unwrap_or(old.page).clamp(1, page_count),
+                    Annotation::text(old.rect, &format!("Deleted: {}", old.text))
+                        .with_color("#FF0000"),
+                ),
+            };
+            comparison.add_annotation(page, &annotation.with_author("Comparison"))?;
+        }
+
+        comparison.save_as(filename)
+    }
+
+    /// Convert the PDF-document to the specified PDF/A format.
+    ///
+    /// Fonts are embedded with `embed_fonts` first; the conversion then adds the output intent
+    /// and the PDF/A identification, declares PDF/A extension schemas for XMP properties outside
+    /// the predefined schemas, converts colors and removes forbidden content.
+    ///
+    /// # Arguments
+    /// * `format` - The PDF/A conformance level [`PdfAFormat`].
+    ///
+    /// # Returns
+    /// Returns `Ok(PdfAConversionReport)` with the applied fixes, including lossy ones, and any violations
+    /// that could not be fixed, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn convert_to_pdfa(&self, format: PdfAFormat) -> Result<PdfAConversionReport, PdfError> {
+        debug_println!("call Document::convert_to_pdfa({format:?})");
+        self.embed_fonts()?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            PDFDocument_ConvertToPdfA(self.pdfdocumentclass, format as c_int, error.as_mut_ptr())
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::convert_to_pdfa({format:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Validate the PDF-document against the specified PDF/A format.
+    ///
+    /// # Arguments
+    /// * `format` - The PDF/A conformance level [`PdfAFormat`].
+    ///
+    /// # Returns
+    /// Returns `Ok(ValidationReport)` with each violated clause, its object and page,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn validate_pdfa(&self, format: PdfAFormat) -> Result<ValidationReport, PdfError> {
+        debug_println!("call Document::validate_pdfa({format:?})");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            PDFDocument_ValidatePdfA(self.pdfdocumentclass, format as c_int, error.as_mut_ptr())
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::validate_pdfa({format:?}): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Validate the PDF-document against PDF/UA (ISO 14289-1).
+    ///
+    /// # Returns
+    /// Returns `Ok(AccessibilityReport)` with missing tags, missing alt text, missing language,
+    /// reading order and title display issues, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn validate_pdfua(&self) -> Result<AccessibilityReport, PdfError> {
+        debug_println!("call Document::validate_pdfua()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_ValidatePdfUA(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::validate_pdfua(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the natural language of the PDF-document.
+    ///
+    /// # Returns
+    /// Returns `Ok(Some(String))` with the language tag (e.g. "en-US"), `Ok(None)` if it is not set,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn language(&self) -> Result<Option<String>, PdfError> {
+        debug_println!("call Document::language()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_get_Language(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let value = if char_ptr.is_null() {
+            None
+        } else {
+            Some(Self::get_string(char_ptr))
         };
         let error_str = Self::get_error(&mut error);
         if error_str.is_empty() {
-            Ok(())
+            Ok(value)
         } else {
-            debug_println!("error Document::page_add_watermark({text:?}): {error_str:?}");
+            debug_println!("error Document::language(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Return the logical structure tree of a tagged PDF-document.
+    ///
+    /// See also: `auto_tag`
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<StructureElement>)` with the top-level elements, empty for an untagged PDF-document,
+    /// or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn structure_tree(&self) -> Result<Vec<StructureElement>, PdfError> {
+        debug_println!("call Document::structure_tree()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_get_StructureTree(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::structure_tree(): {error_str:?}");
             Err(PdfError::CoreExceptionError(error_str))
         }
     }
 
+    /// Turn the PDF-document into a Factur-X / ZUGFeRD e-invoice.
+    ///
+    /// Embeds the invoice XML with the relationship of the profile, writes the Factur-X XMP properties
+    /// and converts the PDF-document to PDF/A-3b, which declares their PDF/A extension schema.
+    /// An e-invoice embedded before is replaced.
+    ///
+    /// # Arguments
+    /// * `xml` - The EN 16931 invoice in UN/CEFACT Cross Industry Invoice (CII) syntax.
+    /// * `profile` - The conformance level of the invoice [`FacturXProfile`].
+    ///
+    /// # Returns
+    /// Returns `Ok(PdfAConversionReport)` with the result of the PDF/A-3b conversion,
+    /// or `Err(PdfError)` if the root element of the XML is not a CII invoice (e.g. a UBL invoice)
+    /// or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn embed_einvoice(
+        &self,
+        xml: &str,
+        profile: FacturXProfile,
+    ) -> Result<PdfAConversionReport, PdfError> {
+        debug_println!("call Document::embed_einvoice({profile:?})");
+        check_cii_invoice(xml)?;
+
+        for attachment in self.attachments()? {
+            if INVOICE_FILE_NAMES.contains(&attachment.name.as_str()) {
+                self.remove_attachment(&attachment.name)?;
+            }
+        }
+        let file_name = profile.file_name();
+        self.add_attachment(
+            file_name,
+            xml.as_bytes(),
+            "text/xml",
+            &format!("Factur-X invoice ({})", profile),
+        )?;
+        self.set_attachment_relationship(file_name, profile.relationship())?;
+
+        for (name, value) in [
+            ("DocumentType", "INVOICE"),
+            ("DocumentFileName", file_name),
+            ("Version", "1.0"),
+            ("ConformanceLevel", profile.conformance_level()),
+        ] {
+            self.set_xmp_property(FACTURX_NAMESPACE, "fx", name, value)?;
+        }
+
+        // Converted last, so that the report describes the final file
+        self.convert_to_pdfa(PdfAFormat::A3b)
+    }
+
+    /// Return the Factur-X / ZUGFeRD / XRechnung e-invoice embedded in the PDF-document.
+    ///
+    /// # Returns
+    /// Returns `Ok(Some(EInvoice))` with the invoice XML and its declared profile, `Ok(None)` if the
+    /// PDF-document has no embedded invoice, or `Err(PdfError)` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn extract_einvoice(&self) -> Result<Option<EInvoice>, PdfError> {
+        debug_println!("call Document::extract_einvoice()");
+        let attachments = self.attachments()?;
+        let Some(file_name) = INVOICE_FILE_NAMES
+            .iter()
+            .find(|name| attachments.iter().any(|a| a.name == **name))
+        else {
+            return Ok(None);
+        };
+
+        let xml = String::from_utf8(self.extract_attachment(file_name)?).map_err(|e| {
+            PdfError::CoreExceptionError(format!("extract_einvoice(): {}: {}", file_name, e))
+        })?;
+
+        let mut profile = None;
+        for namespace in INVOICE_NAMESPACES {
+            if let Some(level) = self.xmp_property(namespace, "ConformanceLevel")? {
+                profile = level.parse().ok();
+                break;
+            }
+        }
+
+        Ok(Some(EInvoice {
+            file_name: file_name.to_string(),
+            xml,
+            profile,
+        }))
+    }
+
     // Generated functions
     generate_fn!(_save, PDFDocument_Save);
     generate_fn!(_save_as, PDFDocument_Save_As, filename: &str);
     generate_fn!(_set_license, PDFDocument_set_License, filename: &str);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_set_xmp, PDFDocument_set_Xmp, xmp: &str);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_strip_metadata, PDFDocument_StripMetadata);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_auto_tag, PDFDocument_AutoTag);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_set_language, PDFDocument_set_Language, language: &str);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_set_image_alt_text, PDFDocument_set_ImageAltText, image_id: &str, alt_text: &str);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_set_structure_role, PDFDocument_set_StructureRole, id: &str, role: &str);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_set_structure_alt_text, PDFDocument_set_StructureAltText, id: &str, alt_text: &str);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_set_structure_actual_text, PDFDocument_set_StructureActualText, id: &str, actual_text: &str);
 
     generate_fn!(_save_docx_enhanced, PDFDocument_Save_DocXEnhanced, filename: &str);
     generate_fn!(_save_docx, PDFDocument_Save_DocX, filename: &str);
@@ -884,6 +3079,8 @@ impl Document {
     generate_fn!(_export_fdf, PDFDocument_Export_Fdf, filename: &str);
     generate_fn!(_export_xfdf, PDFDocument_Export_Xfdf, filename: &str);
     generate_fn!(_export_xml, PDFDocument_Export_Xml, filename: &str);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_rename_field, PDFDocument_RenameField, old_name: &str, new_name: &str);
 
     generate_fn!(_optimize, PDFDocument_Optimize);
     generate_fn!(_optimize_resource, PDFDocument_OptimizeResource);
@@ -902,6 +3099,10 @@ impl Document {
 
     generate_fn!(_remove_annotations, PDFDocument_RemoveAnnotations);
     generate_fn!(_remove_attachments, PDFDocument_RemoveAttachments);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_remove_attachment, PDFDocument_RemoveAttachment, name: &str);
+    #[cfg(feature = "core-preview")]
+    generate_fn!(_set_attachment_relationship, PDFDocument_set_AttachmentRelationship, name: &str, relationship: &str);
     generate_fn!(_remove_blank_pages, PDFDocument_RemoveBlankPages);
     generate_fn!(_remove_bookmarks, PDFDocument_RemoveBookmarks);
     generate_fn!(_remove_hidden_text, PDFDocument_RemoveHiddenText);
@@ -1163,6 +3364,19 @@ impl Document {
         self._export_xml(filename)
     }
 
+    /// Rename an AcroForm field of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `old_name` - The fully qualified name of the field.
+    /// * `new_name` - The new fully qualified name of the field.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the field does not exist, the new name is already used, or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn rename_field(&self, old_name: &str, new_name: &str) -> Result<(), PdfError> {
+        self._rename_field(old_name, new_name)
+    }
+
     /// Optimize PDF-document content.
     ///
     /// # Errors
@@ -1304,6 +3518,36 @@ impl Document {
         self._remove_attachments()
     }
 
+    /// Remove a file embedded in the PDF-document.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the embedded file.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn remove_attachment(&self, name: &str) -> Result<(), PdfError> {
+        self._remove_attachment(name)
+    }
+
+    /// Set the relationship of an embedded file to the PDF-document (AFRelationship) and
+    /// list it among the associated files of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the embedded file.
+    /// * `relationship` - The relationship [`AfRelationship`].
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the embedded file does not exist or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_attachment_relationship(
+        &self,
+        name: &str,
+        relationship: AfRelationship,
+    ) -> Result<(), PdfError> {
+        self._set_attachment_relationship(name, relationship.as_str())
+    }
+
     /// Remove blank pages from PDF-document.
     ///
     /// # Errors
@@ -1376,6 +3620,90 @@ impl Document {
         self._remove_text_footers()
     }
 
+    /// Remove all metadata from PDF-document (information dictionary and XMP metadata).
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn strip_metadata(&self) -> Result<(), PdfError> {
+        self._strip_metadata()
+    }
+
+    /// Generate the logical structure tree of an untagged PDF-document.
+    ///
+    /// Headings, paragraphs, lists, tables and figures are detected from the page content.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn auto_tag(&self) -> Result<(), PdfError> {
+        self._auto_tag()
+    }
+
+    /// Set the natural language of the PDF-document.
+    ///
+    /// # Arguments
+    /// * `language` - The language tag (e.g. "en-US").
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_language(&self, language: &str) -> Result<(), PdfError> {
+        self._set_language(language)
+    }
+
+    /// Set the alternate text of an image, tagging it as a figure if needed.
+    ///
+    /// # Arguments
+    /// * `image_id` - The image identifier from [`ImageInfo`].
+    /// * `alt_text` - The alternate text.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the image does not exist or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_image_alt_text(&self, image_id: &str, alt_text: &str) -> Result<(), PdfError> {
+        self._set_image_alt_text(image_id, alt_text)
+    }
+
+    /// Change the structure type of an element of the structure tree.
+    ///
+    /// # Arguments
+    /// * `id` - The element identifier from [`StructureElement`].
+    /// * `role` - The new structure type (e.g. "H1", "P", "Figure").
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the element does not exist or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_structure_role(&self, id: &str, role: &str) -> Result<(), PdfError> {
+        self._set_structure_role(id, role)
+    }
+
+    /// Set the alternate description of an element of the structure tree.
+    ///
+    /// # Arguments
+    /// * `id` - The element identifier from [`StructureElement`].
+    /// * `alt_text` - The alternate description.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the element does not exist or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_structure_alt_text(&self, id: &str, alt_text: &str) -> Result<(), PdfError> {
+        self._set_structure_alt_text(id, alt_text)
+    }
+
+    /// Set the replacement text of an element of the structure tree.
+    ///
+    /// # Arguments
+    /// * `id` - The element identifier from [`StructureElement`].
+    /// * `actual_text` - The replacement text.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the element does not exist or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn set_structure_actual_text(&self, id: &str, actual_text: &str) -> Result<(), PdfError> {
+        self._set_structure_actual_text(id, actual_text)
+    }
+
     /// Encrypt PDF-document.
     ///
     /// # Arguments
@@ -1528,6 +3856,67 @@ impl Document {
         }
     }
 
+    /// Get the signatures of PDF-document.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SignatureInfo>)` - The signature fields with the signer certificate chain, signing time and signed byte ranges.
+    /// * `Err(PdfError)` - If the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn signatures(&self) -> Result<Vec<SignatureInfo>, PdfError> {
+        debug_println!("call Document::signatures()");
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr =
+            unsafe { PDFDocument_get_Signatures(self.pdfdocumentclass, error.as_mut_ptr()) };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::signatures(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Verify a signature of PDF-document offline.
+    ///
+    /// Checks the digest of the signed byte ranges and validates the certificate chain
+    /// against the trusted certificates of `trust_store`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the signature field, as returned by `signatures`.
+    /// * `trust_store` - The trusted root certificates.
+    ///
+    /// # Returns
+    /// Returns `Ok(VerificationResult)` if the signature was checked, even if it is not valid,
+    /// or `Err(PdfError)` if there is no such signature or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn verify_signature(
+        &self,
+        name: &str,
+        trust_store: &TrustStore,
+    ) -> Result<VerificationResult, PdfError> {
+        debug_println!("call Document::verify_signature({name:?})");
+        let c_string_name = CString::new(name).unwrap();
+        let c_string_trust_store = Self::to_json_c_string(trust_store)?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let char_ptr = unsafe {
+            PDFDocument_VerifySignature(
+                self.pdfdocumentclass,
+                c_string_name.as_ptr(),
+                c_string_trust_store.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let json_str = Self::get_string(char_ptr);
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Self::from_json_str(&json_str)
+        } else {
+            debug_println!("error Document::verify_signature(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
     /// Sign a PDF-document using PKCS#7 digital signatures.
     ///
     /// # Arguments
@@ -1714,6 +4103,207 @@ impl Document {
         }
     }
 
+    /// Sign a PDF-document with a certificate chain and private key in PEM or DER format.
+    ///
+    /// # Arguments
+    /// * `key` - The certificate chain and private key [`SigningKey`].
+    /// * `options` - The appearance, digest algorithm and format of the signature [`SignatureOptions`].
+    /// * `filename` - The path to the resulting PDF-document with signature.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the key does not match the signer certificate or the operation fails.
+    #[cfg(feature = "core-preview")]
+    pub fn sign_with_certificate(
+        &self,
+        key: &SigningKey,
+        options: &SignatureOptions,
+        filename: &str,
+    ) -> Result<(), PdfError> {
+        debug_println!("call Document::sign_with_certificate({options:?}, {filename:?})");
+        let c_string_key = Self::to_json_c_string(key)?;
+        let c_string_options = Self::to_json_c_string(options)?;
+        let c_string_filename = CString::new(filename).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_SignWithCertificate(
+                self.pdfdocumentclass,
+                c_string_key.as_ptr(),
+                c_string_options.as_ptr(),
+                c_string_filename.as_ptr(),
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::sign_with_certificate(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    /// Sign a PDF-document with an external signer, e.g. a hardware security module.
+    ///
+    /// The signature field and a placeholder for the signature value are written to `filename`,
+    /// the digest of the signed byte ranges is passed to `signer`, and the returned CMS is
+    /// embedded into the placeholder. The private key is never passed to this crate.
+    ///
+    /// # Arguments
+    /// * `signer` - The external signer [`Signer`].
+    /// * `options` - The appearance, digest algorithm and format of the signature [`SignatureOptions`];
+    ///   the format must be `SignatureFormat::Pkcs7Detached` or `SignatureFormat::PadesBaseline`.
+    /// * `filename` - The path to the resulting PDF-document with signature.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if `Signer::signature_size` does not fit the core library, the signer fails,
+    /// the CMS exceeds `Signer::signature_size` or the operation fails. `filename` is removed
+    /// if it was written by this call and is left unchanged if the signature could not be prepared.
+    #[cfg(feature = "core-preview")]
+    pub fn sign_external(
+        &self,
+        signer: &dyn Signer,
+        options: &SignatureOptions,
+        filename: &str,
+    ) -> Result<(), PdfError> {
+        debug_println!("call Document::sign_external({options:?}, {filename:?})");
+        if options.format == SignatureFormat::Pkcs7 {
+            return Err(PdfError::CoreExceptionError(
+                "external signing requires a detached signature format".to_string(),
+            ));
+        }
+        let size = signer.signature_size();
+        let c_size = c_int::try_from(size).map_err(|_| {
+            PdfError::CoreExceptionError(format!("signature size of {} bytes is too large", size))
+        })?;
+
+        // Nothing is written to `filename` if the preparation fails
+        let digest = self.prepare_signature(options, c_size, filename)?;
+        let result = signer
+            .sign(&digest, options.digest, options.format)
+            .and_then(|cms| {
+                if cms.len() > size {
+                    Err(PdfError::CoreExceptionError(format!(
+                        "CMS of {} bytes exceeds the reserved signature size of {} bytes",
+                        cms.len(),
+                        size
+                    )))
+                } else {
+                    Self::embed_signature(filename, &cms)
+                }
+            });
+        if result.is_err() {
+            let _ = std::fs::remove_file(filename);
+        }
+        result
+    }
+
+    // Write the PDF-document with an empty signature of `size` bytes and return the digest to sign.
+    #[cfg(feature = "core-preview")]
+    fn prepare_signature(
+        &self,
+        options: &SignatureOptions,
+        size: c_int,
+        filename: &str,
+    ) -> Result<Vec<u8>, PdfError> {
+        let c_string_options = Self::to_json_c_string(options)?;
+        let c_string_filename = CString::new(filename).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let mut buf: *mut c_uchar = std::ptr::null_mut();
+        let mut buf_size: i32 = 0;
+
+        unsafe {
+            PDFDocument_PrepareSignature(
+                self.pdfdocumentclass,
+                c_string_options.as_ptr(),
+                size,
+                c_string_filename.as_ptr(),
+                &mut buf,
+                &mut buf_size,
+                error.as_mut_ptr(),
+            );
+        }
+
+        let error_str = Self::get_error(&mut error);
+
+        if error_str.is_empty() && !buf.is_null() && buf_size > 0 {
+            let digest = unsafe { std::slice::from_raw_parts(buf, buf_size as usize).to_vec() };
+            unsafe { c_free_buffer(buf.cast()) };
+            Ok(digest)
+        } else {
+            if !buf.is_null() {
+                unsafe { c_free_buffer(buf.cast()) };
+            }
+
+            debug_println!("error Document::prepare_signature(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    // Write the CMS into the empty signature of the PDF-document written by `prepare_signature`.
+    #[cfg(feature = "core-preview")]
+    fn embed_signature(filename: &str, cms: &[u8]) -> Result<(), PdfError> {
+        let c_string_filename = CString::new(filename).unwrap();
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        unsafe {
+            PDFDocument_EmbedSignature(
+                c_string_filename.as_ptr(),
+                cms.as_ptr(),
+                cms.len() as c_int,
+                error.as_mut_ptr(),
+            )
+        };
+        let error_str = Self::get_error(&mut error);
+        if error_str.is_empty() {
+            Ok(())
+        } else {
+            debug_println!("error Document::embed_signature(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
+    // Create a detached CMS for the digest with the private key, used by `SoftwareSigner`.
+    #[cfg(feature = "core-preview")]
+    pub(crate) fn create_cms(
+        key: &SigningKey,
+        digest: &[u8],
+        algorithm: DigestAlgorithm,
+        format: SignatureFormat,
+    ) -> Result<Vec<u8>, PdfError> {
+        let c_string_key = Self::to_json_c_string(key)?;
+        let c_string_options =
+            Self::to_json_c_string(&serde_json::json!({ "digest": algorithm, "format": format }))?;
+        let mut error: std::mem::MaybeUninit<*const c_char> = std::mem::MaybeUninit::uninit();
+        let mut buf: *mut c_uchar = std::ptr::null_mut();
+        let mut size: i32 = 0;
+
+        unsafe {
+            PDFDocument_CreateCms(
+                c_string_key.as_ptr(),
+                digest.as_ptr(),
+                digest.len() as c_int,
+                c_string_options.as_ptr(),
+                &mut buf,
+                &mut size,
+                error.as_mut_ptr(),
+            );
+        }
+
+        let error_str = Self::get_error(&mut error);
+
+        if error_str.is_empty() && !buf.is_null() && size > 0 {
+            let cms = unsafe { std::slice::from_raw_parts(buf, size as usize).to_vec() };
+            unsafe { c_free_buffer(buf.cast()) };
+            Ok(cms)
+        } else {
+            if !buf.is_null() {
+                unsafe { c_free_buffer(buf.cast()) };
+            }
+
+            debug_println!("error Document::create_cms(): {error_str:?}");
+            Err(PdfError::CoreExceptionError(error_str))
+        }
+    }
+
     /// Remove signs from PDF-document.
     ///
     /// # Arguments
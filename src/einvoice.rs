@@ -0,0 +1,186 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attachment::AfRelationship;
+use crate::errors::PdfError;
+
+/// The XMP namespace of the Factur-X (ZUGFeRD 2.x) properties.
+pub const FACTURX_NAMESPACE: &str = "urn:factur-x:pdfa:CrossIndustryDocument:invoice:1p0#";
+
+// XMP namespaces of the invoice properties, including earlier ZUGFeRD versions, most recent first.
+#[cfg(feature = "core-preview")]
+pub(crate) const INVOICE_NAMESPACES: [&str; 3] = [
+    FACTURX_NAMESPACE,
+    "urn:zugferd:pdfa:CrossIndustryDocument:invoice:2p0#",
+    "urn:ferd:pdfa:CrossIndustryDocument:invoice:1p0#",
+];
+
+// Names of the embedded invoice XML used by Factur-X, XRechnung and ZUGFeRD 1.0.
+#[cfg(feature = "core-preview")]
+pub(crate) const INVOICE_FILE_NAMES: [&str; 4] = [
+    "factur-x.xml",
+    "xrechnung.xml",
+    "zugferd-invoice.xml",
+    "ZUGFeRD-invoice.xml",
+];
+
+// The namespace of the root element of a UN/CEFACT Cross Industry Invoice, the syntax of Factur-X.
+#[cfg(feature = "core-preview")]
+const CII_NAMESPACE: &str = "urn:un:unece:uncefact:data:standard:CrossIndustryInvoice:100";
+
+// Namespaces of the root elements of UBL invoices and credit notes.
+#[cfg(feature = "core-preview")]
+const UBL_NAMESPACES: [&str; 2] = [
+    "urn:oasis:names:specification:ubl:schema:xsd:Invoice-2",
+    "urn:oasis:names:specification:ubl:schema:xsd:CreditNote-2",
+];
+
+// Checks that the root element of the XML is a Cross Industry Invoice.
+#[cfg(feature = "core-preview")]
+pub(crate) fn check_cii_invoice(xml: &str) -> Result<(), PdfError> {
+    let reason = match root_element(xml) {
+        Some(("CrossIndustryInvoice", Some(CII_NAMESPACE))) => return Ok(()),
+        Some((_, Some(namespace))) if UBL_NAMESPACES.contains(&namespace) => {
+            "the XML is a UBL invoice, Factur-X requires the Cross Industry Invoice (CII) syntax"
+                .to_string()
+        }
+        Some((name, namespace)) => format!(
+            "the root element {{{}}}{} is not a Cross Industry Invoice",
+            namespace.unwrap_or_default(),
+            name
+        ),
+        None => "the XML has no root element".to_string(),
+    };
+    Err(PdfError::CoreExceptionError(format!(
+        "embed_einvoice(): {}",
+        reason
+    )))
+}
+
+// Returns the local name and the namespace of the root element of the XML.
+#[cfg(feature = "core-preview")]
+fn root_element(xml: &str) -> Option<(&str, Option<&str>)> {
+    // Skip the XML declaration, processing instructions, comments and the document type declaration
+    let mut rest = xml.trim_start_matches('\u{feff}');
+    loop {
+        rest = rest.trim_start();
+        if let Some(pi) = rest.strip_prefix("<?") {
+            rest = &pi[pi.find("?>")? + 2..];
+        } else if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = &comment[comment.find("-->")? + 3..];
+        } else if let Some(declaration) = rest.strip_prefix("<!") {
+            rest = &declaration[declaration.find('>')? + 1..];
+        } else {
+            break;
+        }
+    }
+
+    let tag = rest.strip_prefix('<')?;
+    let (qualified_name, mut attributes) =
+        tag.split_at(tag.find(|c: char| c.is_whitespace() || c == '/' || c == '>')?);
+    let (xmlns, name) = match qualified_name.split_once(':') {
+        Some((prefix, name)) => (format!("xmlns:{}", prefix), name),
+        None => ("xmlns".to_string(), qualified_name),
+    };
+
+    let mut namespace = None;
+    loop {
+        attributes = attributes.trim_start();
+        if attributes.starts_with('>') || attributes.starts_with("/>") {
+            return Some((name, namespace));
+        }
+        let (attribute, value) = attributes.split_once('=')?;
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        let end = value.find(quote)?;
+        if attribute.trim() == xmlns {
+            namespace = Some(&value[..end]);
+        }
+        attributes = &value[end + 1..];
+    }
+}
+
+/// FacturXProfile is the Factur-X / ZUGFeRD conformance level of an e-invoice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FacturXProfile {
+    Minimum,
+    BasicWl,
+    Basic,
+    /// EN 16931 (ZUGFeRD "COMFORT").
+    En16931,
+    Extended,
+    XRechnung,
+}
+
+impl FacturXProfile {
+    /// Return the conformance level written to the XMP metadata (e.g. `"EN 16931"`).
+    pub fn conformance_level(&self) -> &'static str {
+        match self {
+            FacturXProfile::Minimum => "MINIMUM",
+            FacturXProfile::BasicWl => "BASIC WL",
+            FacturXProfile::Basic => "BASIC",
+            FacturXProfile::En16931 => "EN 16931",
+            FacturXProfile::Extended => "EXTENDED",
+            FacturXProfile::XRechnung => "XRECHNUNG",
+        }
+    }
+
+    /// Return the name of the embedded invoice XML.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            FacturXProfile::XRechnung => "xrechnung.xml",
+            _ => "factur-x.xml",
+        }
+    }
+
+    /// Return the relationship of the invoice XML to the PDF-document.
+    ///
+    /// MINIMUM and BASIC WL invoices are not complete invoices, so their XML is `Data`;
+    /// the XML of the other profiles is an `Alternative` representation of the invoice.
+    pub fn relationship(&self) -> AfRelationship {
+        match self {
+            FacturXProfile::Minimum | FacturXProfile::BasicWl => AfRelationship::Data,
+            _ => AfRelationship::Alternative,
+        }
+    }
+}
+
+impl FromStr for FacturXProfile {
+    type Err = PdfError;
+
+    /// Parse a conformance level of the XMP metadata, case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "MINIMUM" => Ok(FacturXProfile::Minimum),
+            "BASIC WL" => Ok(FacturXProfile::BasicWl),
+            "BASIC" => Ok(FacturXProfile::Basic),
+            "EN 16931" | "COMFORT" => Ok(FacturXProfile::En16931),
+            "EXTENDED" => Ok(FacturXProfile::Extended),
+            "XRECHNUNG" => Ok(FacturXProfile::XRechnung),
+            _ => Err(PdfError::CoreExceptionError(format!(
+                "unknown Factur-X conformance level: {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for FacturXProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.conformance_level())
+    }
+}
+
+/// EInvoice is an e-invoice extracted by `Document::extract_einvoice`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EInvoice {
+    /// The name of the embedded invoice XML.
+    pub file_name: String,
+
+    /// The invoice XML.
+    pub xml: String,
+
+    /// The conformance level declared in the XMP metadata, `None` if absent or unknown.
+    pub profile: Option<FacturXProfile>,
+}
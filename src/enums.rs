@@ -57,3 +57,55 @@ pub enum CryptoAlgorithm {
     /// AES with key length 256.
     AESx256 = 3,
 }
+
+/// An enumeration of possible encodings of extracted images.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub enum ImageEncoding {
+    /// The stored encoding for JPEG and JPEG 2000 images, PNG for other images.
+    Native = 0,
+    /// PNG.
+    Png = 1,
+}
+
+/// An enumeration of possible raster image formats.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub enum ImageFormat {
+    /// PNG.
+    Png = 0,
+    /// JPEG.
+    Jpg = 1,
+    /// BMP.
+    Bmp = 2,
+    /// TIFF.
+    Tiff = 3,
+}
+
+/// An enumeration of possible PDF/A conformance levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub enum PdfAFormat {
+    /// PDF/A-1a (ISO 19005-1, accessible).
+    A1a = 0,
+    /// PDF/A-1b (ISO 19005-1, basic).
+    A1b = 1,
+    /// PDF/A-2a (ISO 19005-2, accessible).
+    A2a = 2,
+    /// PDF/A-2b (ISO 19005-2, basic).
+    A2b = 3,
+    /// PDF/A-2u (ISO 19005-2, Unicode).
+    A2u = 4,
+    /// PDF/A-3a (ISO 19005-3, accessible).
+    A3a = 5,
+    /// PDF/A-3b (ISO 19005-3, basic).
+    A3b = 6,
+    /// PDF/A-3u (ISO 19005-3, Unicode).
+    A3u = 7,
+    /// PDF/A-4 (ISO 19005-4).
+    A4 = 8,
+    /// PDF/A-4e (ISO 19005-4, engineering).
+    A4e = 9,
+    /// PDF/A-4f (ISO 19005-4, embedded files).
+    A4f = 10,
+}
@@ -491,6 +491,341 @@ extern "C" {
     );
 }
 
+// Functions of the core library that no core release exports yet, including the bundled library.
+// Their signatures and JSON formats are not pinned to a core version and have never been linked.
+#[cfg(feature = "core-preview")]
+extern "C" {
+    pub fn PDFDocument_Redact(
+        pdfdocumentclass: *const c_void,
+        marks: *const c_char,
+        appearance: *const c_char,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_PrepareSignature(
+        pdfdocumentclass: *const c_void,
+        options: *const c_char,
+        size: c_int,
+        filename: *const c_char,
+        buffer_out: *mut *mut u8,
+        size_out: *mut c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_EmbedSignature(
+        filename: *const c_char,
+        cms: *const u8,
+        cms_length: c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_CreateCms(
+        key: *const c_char,
+        digest: *const u8,
+        digest_length: c_int,
+        options: *const c_char,
+        buffer_out: *mut *mut u8,
+        size_out: *mut c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_SignWithCertificate(
+        pdfdocumentclass: *const c_void,
+        key: *const c_char,
+        options: *const c_char,
+        filename: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_get_Signatures(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_VerifySignature(
+        pdfdocumentclass: *const c_void,
+        name: *const c_char,
+        truststore: *const c_char,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_set_AttachmentRelationship(
+        pdfdocumentclass: *const c_void,
+        name: *const c_char,
+        relationship: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_get_StructureTree(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_set_StructureRole(
+        pdfdocumentclass: *const c_void,
+        id: *const c_char,
+        role: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_set_StructureAltText(
+        pdfdocumentclass: *const c_void,
+        id: *const c_char,
+        altText: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_set_StructureActualText(
+        pdfdocumentclass: *const c_void,
+        id: *const c_char,
+        actualText: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_ValidatePdfUA(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_AutoTag(pdfdocumentclass: *const c_void, error: *mut *const c_char);
+    pub fn PDFDocument_get_Language(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_set_Language(
+        pdfdocumentclass: *const c_void,
+        language: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_set_ImageAltText(
+        pdfdocumentclass: *const c_void,
+        id: *const c_char,
+        altText: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_ConvertToPdfA(
+        pdfdocumentclass: *const c_void,
+        format: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_ValidatePdfA(
+        pdfdocumentclass: *const c_void,
+        format: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_CompareText(
+        pdfdocumentclass: *const c_void,
+        otherpdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Page_get_Rect(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Page_RenderRegion(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        rect: *const c_char,
+        zoom: c_double,
+        format: c_int,
+        buffer_out: *mut *mut u8,
+        size_out: *mut c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_Page_Thumbnail(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        maxWidth: c_int,
+        maxHeight: c_int,
+        format: c_int,
+        buffer_out: *mut *mut u8,
+        size_out: *mut c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_Page_Render(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        options: *const c_char,
+        buffer_out: *mut *mut u8,
+        size_out: *mut c_int,
+        width_out: *mut c_int,
+        height_out: *mut c_int,
+        stride_out: *mut c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_ExtractTables(
+        pdfdocumentclass: *const c_void,
+        pagerange: *const c_char,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Page_get_Images(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_ExtractImage(
+        pdfdocumentclass: *const c_void,
+        id: *const c_char,
+        encoding: c_int,
+        buffer_out: *mut *mut u8,
+        size_out: *mut c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_Open_Memory(
+        data: *const u8,
+        dataLen: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_void;
+    pub fn PDFDocument_RenameField(
+        pdfdocumentclass: *const c_void,
+        oldName: *const c_char,
+        newName: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_has_Xfa(pdfdocumentclass: *const c_void, error: *mut *const c_char)
+        -> c_int;
+    pub fn PDFDocument_get_XfaData(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_ConvertXfaToAcroForm(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_FlattenWithOptions(
+        pdfdocumentclass: *const c_void,
+        options: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_Page_AddFormField(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        field: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_Import_Fdf(
+        pdfdocumentclass: *const c_void,
+        filename: *const c_char,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Import_Fdf_Memory(
+        pdfdocumentclass: *const c_void,
+        data: *const u8,
+        dataLen: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Import_Xfdf(
+        pdfdocumentclass: *const c_void,
+        filename: *const c_char,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Import_Xfdf_Memory(
+        pdfdocumentclass: *const c_void,
+        data: *const u8,
+        dataLen: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Import_Xml(
+        pdfdocumentclass: *const c_void,
+        filename: *const c_char,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Import_Xml_Memory(
+        pdfdocumentclass: *const c_void,
+        data: *const u8,
+        dataLen: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_get_FormFields(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_set_FormFields(
+        pdfdocumentclass: *const c_void,
+        values: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_Page_get_Annotations(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_Page_AddAnnotation(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        annotation: *const c_char,
+        error: *mut *const c_char,
+    ) -> c_int;
+    pub fn PDFDocument_Page_set_Annotation(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        index: c_int,
+        annotation: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_Page_DeleteAnnotation(
+        pdfdocumentclass: *const c_void,
+        num: c_int,
+        index: c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_get_Attachments(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_ExtractAttachment(
+        pdfdocumentclass: *const c_void,
+        name: *const c_char,
+        buffer_out: *mut *mut u8,
+        size_out: *mut c_int,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_AddAttachment(
+        pdfdocumentclass: *const c_void,
+        name: *const c_char,
+        data: *const u8,
+        dataLen: c_int,
+        mimeType: *const c_char,
+        description: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_RemoveAttachment(
+        pdfdocumentclass: *const c_void,
+        name: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_get_Bookmarks(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_set_Bookmarks(
+        pdfdocumentclass: *const c_void,
+        bookmarks: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_get_Metadata(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_set_Metadata(
+        pdfdocumentclass: *const c_void,
+        metadata: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_get_Xmp(
+        pdfdocumentclass: *const c_void,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_set_Xmp(
+        pdfdocumentclass: *const c_void,
+        xmp: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_get_XmpProperty(
+        pdfdocumentclass: *const c_void,
+        namespaceUri: *const c_char,
+        name: *const c_char,
+        error: *mut *const c_char,
+    ) -> *const c_char;
+    pub fn PDFDocument_set_XmpProperty(
+        pdfdocumentclass: *const c_void,
+        namespaceUri: *const c_char,
+        prefix: *const c_char,
+        name: *const c_char,
+        value: *const c_char,
+        error: *mut *const c_char,
+    );
+    pub fn PDFDocument_StripMetadata(pdfdocumentclass: *const c_void, error: *mut *const c_char);
+}
+
 extern "C" {
     pub fn c_free_string(str: *mut c_char);
     pub fn c_free_buffer(ptr: *mut c_void);
@@ -0,0 +1,76 @@
+use serde::Serialize;
+
+/// FlattenTarget defines which interactive content is merged into the page content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum FlattenTarget {
+    /// Form fields and annotations.
+    #[default]
+    #[serde(rename = "all")]
+    All,
+    /// Form field widgets only, annotations remain interactive.
+    #[serde(rename = "formfields")]
+    FormFields,
+    /// Annotations other than form field widgets only, form fields remain fillable.
+    #[serde(rename = "annotations")]
+    Annotations,
+}
+
+/// FlattenOptions selects the content flattened by `Document::flatten_with_options`.
+///
+/// The default options flatten everything, as `Document::flatten` does.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FlattenOptions {
+    #[serde(rename = "target")]
+    pub target: FlattenTarget,
+
+    /// The fully qualified names of the form fields to flatten, empty for all fields.
+    #[serde(rename = "fields")]
+    pub fields: Vec<String>,
+
+    /// The page numbers (1-based) to flatten, empty for all pages.
+    #[serde(rename = "pages")]
+    pub pages: Vec<i32>,
+
+    /// Whether to regenerate the appearances of form fields from their values before flattening.
+    #[serde(rename = "regenerateappearances")]
+    pub regenerate_appearances: bool,
+}
+
+impl FlattenOptions {
+    /// Flatten form fields only.
+    pub fn form_fields() -> Self {
+        FlattenOptions {
+            target: FlattenTarget::FormFields,
+            ..Default::default()
+        }
+    }
+
+    /// Flatten annotations only.
+    pub fn annotations() -> Self {
+        FlattenOptions {
+            target: FlattenTarget::Annotations,
+            ..Default::default()
+        }
+    }
+
+    /// Flatten only the specified form fields.
+    pub fn fields(names: &[&str]) -> Self {
+        FlattenOptions {
+            target: FlattenTarget::FormFields,
+            fields: names.iter().map(|name| name.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Return the options restricted to the specified pages (1-based).
+    pub fn with_pages(mut self, pages: &[i32]) -> Self {
+        self.pages = pages.to_vec();
+        self
+    }
+
+    /// Return the options with appearance regeneration enabled.
+    pub fn with_regenerated_appearances(mut self) -> Self {
+        self.regenerate_appearances = true;
+        self
+    }
+}
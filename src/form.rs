@@ -0,0 +1,522 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "core-preview")]
+use std::collections::HashMap;
+use std::fmt;
+
+#[cfg(feature = "core-preview")]
+use crate::errors::PdfError;
+use crate::rect::Rect;
+
+/// FieldType is the type of an AcroForm field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldType {
+    #[serde(rename = "text")]
+    Text,
+    #[serde(rename = "checkbox")]
+    CheckBox,
+    #[serde(rename = "radio")]
+    RadioButton,
+    #[serde(rename = "combobox")]
+    ComboBox,
+    #[serde(rename = "listbox")]
+    ListBox,
+    #[serde(rename = "pushbutton")]
+    PushButton,
+    #[serde(rename = "signature")]
+    Signature,
+}
+
+bitflags! {
+    /// Bitflag set representing AcroForm field flags (the `Ff` entry of the field dictionary).
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+    pub struct FieldFlags: u32 {
+        const READ_ONLY         = 1 << 0;  // 1
+        const REQUIRED          = 1 << 1;  // 2
+        const NO_EXPORT         = 1 << 2;  // 4
+        const MULTILINE         = 1 << 12; // 4096
+        const PASSWORD          = 1 << 13; // 8192
+        const NO_TOGGLE_TO_OFF  = 1 << 14; // 16384
+        const COMBO             = 1 << 17; // 131072
+        const EDIT              = 1 << 18; // 262144
+        const MULTI_SELECT      = 1 << 21; // 2097152
+        const DO_NOT_SPELL_CHECK = 1 << 22; // 4194304
+    }
+}
+
+/// Converts an `u32` bitmask into `FieldFlags`, keeping only known bits.
+impl From<u32> for FieldFlags {
+    fn from(value: u32) -> Self {
+        FieldFlags::from_bits_truncate(value)
+    }
+}
+
+impl Serialize for FieldFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(FieldFlags::from(u32::deserialize(deserializer)?))
+    }
+}
+
+/// FieldValue is the value of an AcroForm field.
+///
+/// Text fields, radio buttons and combo boxes take `Text`, check boxes take `Bool`,
+/// and list boxes take `List` (or `Text` for a single selection).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FieldValue {
+    Bool(bool),
+    Text(String),
+    List(Vec<String>),
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        FieldValue::Bool(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        FieldValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        FieldValue::Text(value)
+    }
+}
+
+impl From<Vec<String>> for FieldValue {
+    fn from(value: Vec<String>) -> Self {
+        FieldValue::List(value)
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FieldValue::Bool(value) => write!(f, "{}", value),
+            FieldValue::Text(value) => write!(f, "{}", value),
+            FieldValue::List(values) => write!(f, "{}", values.join(", ")),
+        }
+    }
+}
+
+/// Widget is a visual representation of an AcroForm field on a page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Widget {
+    /// The page number (1-based).
+    #[serde(rename = "page")]
+    pub page: i32,
+
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+}
+
+/// FormField describes an AcroForm field of the PDF-document.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FormField {
+    /// The fully qualified field name (e.g. `"applicant.name"`).
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub field_type: FieldType,
+
+    #[serde(rename = "value", default)]
+    pub value: Option<FieldValue>,
+
+    #[serde(rename = "defaultvalue", default)]
+    pub default_value: Option<FieldValue>,
+
+    /// The export values of check boxes and radio buttons, or the items of combo and list boxes.
+    #[serde(rename = "options", default)]
+    pub options: Vec<String>,
+
+    #[serde(rename = "flags", default)]
+    pub flags: FieldFlags,
+
+    #[serde(rename = "widgets", default)]
+    pub widgets: Vec<Widget>,
+}
+
+/// RadioButtonOption is a button of a radio group with its export value.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RadioButtonOption {
+    #[serde(rename = "value")]
+    pub value: String,
+
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+}
+
+/// FieldKind is the type of a new AcroForm field with its type-specific properties.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum FieldKind {
+    #[serde(rename = "text")]
+    Text {
+        #[serde(rename = "multiline")]
+        multiline: bool,
+        #[serde(rename = "password")]
+        password: bool,
+        #[serde(rename = "maxlength")]
+        max_length: Option<u32>,
+    },
+    #[serde(rename = "checkbox")]
+    CheckBox {
+        /// The value of the field when checked.
+        #[serde(rename = "exportvalue")]
+        export_value: String,
+    },
+    #[serde(rename = "radio")]
+    RadioGroup {
+        #[serde(rename = "buttons")]
+        buttons: Vec<RadioButtonOption>,
+    },
+    #[serde(rename = "combobox")]
+    ComboBox {
+        #[serde(rename = "options")]
+        options: Vec<String>,
+        #[serde(rename = "editable")]
+        editable: bool,
+    },
+    #[serde(rename = "listbox")]
+    ListBox {
+        #[serde(rename = "options")]
+        options: Vec<String>,
+        #[serde(rename = "multiselect")]
+        multi_select: bool,
+    },
+    #[serde(rename = "pushbutton")]
+    PushButton {
+        #[serde(rename = "caption")]
+        caption: String,
+    },
+    #[serde(rename = "signature")]
+    Signature,
+}
+
+/// FieldFont defines the text appearance of a new AcroForm field.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldFont {
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// The font size, `0.0` for auto-size.
+    #[serde(rename = "size")]
+    pub size: f64,
+
+    /// The text color (hexadecimal format "#RRGGBB").
+    #[serde(rename = "color")]
+    pub color: String,
+}
+
+impl Default for FieldFont {
+    fn default() -> Self {
+        FieldFont {
+            name: "Helvetica".to_string(),
+            size: 10.0,
+            color: "#000000".to_string(),
+        }
+    }
+}
+
+/// FieldDefinition describes an AcroForm field to add to a page with `Document::add_form_field`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldDefinition {
+    /// The fully qualified field name.
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "kind")]
+    pub kind: FieldKind,
+
+    /// The widget rectangle (radio groups use the rectangles of their buttons instead).
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+
+    #[serde(rename = "defaultvalue")]
+    pub default_value: Option<FieldValue>,
+
+    #[serde(rename = "flags")]
+    pub flags: FieldFlags,
+
+    /// The text appearance, `None` for the default font.
+    #[serde(rename = "font")]
+    pub font: Option<FieldFont>,
+
+    #[serde(rename = "tooltip")]
+    pub tooltip: Option<String>,
+}
+
+impl FieldDefinition {
+    /// Create a field definition of the specified kind.
+    pub fn new(name: &str, kind: FieldKind, rect: Rect) -> Self {
+        FieldDefinition {
+            name: name.to_string(),
+            kind,
+            rect,
+            default_value: None,
+            flags: FieldFlags::empty(),
+            font: None,
+            tooltip: None,
+        }
+    }
+
+    /// Create a single-line text field.
+    pub fn text(name: &str, rect: Rect) -> Self {
+        Self::new(
+            name,
+            FieldKind::Text {
+                multiline: false,
+                password: false,
+                max_length: None,
+            },
+            rect,
+        )
+    }
+
+    /// Create a check box with the export value `"Yes"`.
+    pub fn checkbox(name: &str, rect: Rect) -> Self {
+        Self::new(
+            name,
+            FieldKind::CheckBox {
+                export_value: "Yes".to_string(),
+            },
+            rect,
+        )
+    }
+
+    /// Create a radio group from `(export value, rectangle)` pairs, one per button.
+    pub fn radio_group(name: &str, buttons: &[(&str, Rect)]) -> Self {
+        let buttons: Vec<RadioButtonOption> = buttons
+            .iter()
+            .map(|(value, rect)| RadioButtonOption {
+                value: value.to_string(),
+                rect: *rect,
+            })
+            .collect();
+        let rect = buttons.first().map(|b| b.rect).unwrap_or_default();
+        Self::new(name, FieldKind::RadioGroup { buttons }, rect)
+    }
+
+    /// Create a combo box (drop-down list) with the specified items.
+    pub fn combo_box(name: &str, rect: Rect, options: &[&str]) -> Self {
+        Self::new(
+            name,
+            FieldKind::ComboBox {
+                options: options.iter().map(|o| o.to_string()).collect(),
+                editable: false,
+            },
+            rect,
+        )
+    }
+
+    /// Create a list box with the specified items.
+    pub fn list_box(name: &str, rect: Rect, options: &[&str]) -> Self {
+        Self::new(
+            name,
+            FieldKind::ListBox {
+                options: options.iter().map(|o| o.to_string()).collect(),
+                multi_select: false,
+            },
+            rect,
+        )
+    }
+
+    /// Create a push button with the specified caption.
+    pub fn push_button(name: &str, rect: Rect, caption: &str) -> Self {
+        Self::new(
+            name,
+            FieldKind::PushButton {
+                caption: caption.to_string(),
+            },
+            rect,
+        )
+    }
+
+    /// Create an unsigned signature field.
+    pub fn signature(name: &str, rect: Rect) -> Self {
+        Self::new(name, FieldKind::Signature, rect)
+    }
+
+    /// Return the field definition with the specified default value.
+    pub fn with_default_value(mut self, value: impl Into<FieldValue>) -> Self {
+        self.default_value = Some(value.into());
+        self
+    }
+
+    /// Return the field definition with the specified flags added.
+    pub fn with_flags(mut self, flags: FieldFlags) -> Self {
+        self.flags |= flags;
+        self
+    }
+
+    /// Return the field definition marked as required.
+    pub fn required(self) -> Self {
+        self.with_flags(FieldFlags::REQUIRED)
+    }
+
+    /// Return the field definition marked as read-only.
+    pub fn read_only(self) -> Self {
+        self.with_flags(FieldFlags::READ_ONLY)
+    }
+
+    /// Return the field definition with the specified font.
+    pub fn with_font(mut self, font: FieldFont) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Return the field definition with the specified tooltip.
+    pub fn with_tooltip(mut self, tooltip: &str) -> Self {
+        self.tooltip = Some(tooltip.to_string());
+        self
+    }
+}
+
+// Converts a serializable value into field values keyed by fully qualified field name.
+// Nested objects produce dotted names, numbers are converted to text, and `null` values are skipped.
+#[cfg(feature = "core-preview")]
+pub(crate) fn field_values_from<T: Serialize>(
+    data: &T,
+) -> Result<HashMap<String, FieldValue>, PdfError> {
+    let value =
+        serde_json::to_value(data).map_err(|e| PdfError::CoreExceptionError(e.to_string()))?;
+    let mut values = HashMap::new();
+    match value {
+        serde_json::Value::Object(map) => collect_field_values("", map, &mut values)?,
+        _ => {
+            return Err(PdfError::CoreExceptionError(
+                "form data must serialize to a map or struct".to_string(),
+            ))
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(feature = "core-preview")]
+fn collect_field_values(
+    prefix: &str,
+    map: serde_json::Map<String, serde_json::Value>,
+    values: &mut HashMap<String, FieldValue>,
+) -> Result<(), PdfError> {
+    for (key, value) in map {
+        let name = if prefix.is_empty() {
+            key
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        let field_value = match value {
+            serde_json::Value::Null => continue,
+            serde_json::Value::Bool(b) => FieldValue::Bool(b),
+            serde_json::Value::Number(n) => FieldValue::Text(n.to_string()),
+            serde_json::Value::String(s) => FieldValue::Text(s),
+            serde_json::Value::Array(items) => FieldValue::List(
+                items
+                    .into_iter()
+                    .map(|item| match item {
+                        serde_json::Value::String(s) => Ok(s),
+                        serde_json::Value::Number(n) => Ok(n.to_string()),
+                        _ => Err(PdfError::CoreExceptionError(format!(
+                            "unsupported list item in field \"{}\"",
+                            name
+                        ))),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            serde_json::Value::Object(nested) => {
+                collect_field_values(&name, nested, values)?;
+                continue;
+            }
+        };
+        values.insert(name, field_value);
+    }
+    Ok(())
+}
+
+/// InvalidValue describes a value that failed type validation on import.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InvalidValue {
+    /// The fully qualified field name.
+    #[serde(rename = "field")]
+    pub field: String,
+
+    /// The rejected value as text.
+    #[serde(rename = "value")]
+    pub value: String,
+
+    /// The reason why the value was rejected.
+    #[serde(rename = "reason")]
+    pub reason: String,
+}
+
+/// ImportReport lists the result of importing form data into AcroForm fields.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ImportReport {
+    /// The fields that were filled.
+    #[serde(rename = "imported", default)]
+    pub imported: Vec<String>,
+
+    /// The names in the form data that do not match any field.
+    #[serde(rename = "unknownfields", default)]
+    pub unknown_fields: Vec<String>,
+
+    /// The values that failed type validation and were not imported.
+    #[serde(rename = "invalidvalues", default)]
+    pub invalid_values: Vec<InvalidValue>,
+}
+
+impl ImportReport {
+    /// Return true if all values in the form data were imported.
+    pub fn is_complete(&self) -> bool {
+        self.unknown_fields.is_empty() && self.invalid_values.is_empty()
+    }
+}
+
+// Checks that the value can be assigned to the field.
+// Returns the reason if the value does not match the field type or options.
+#[cfg(feature = "core-preview")]
+pub(crate) fn validate_field_value(field: &FormField, value: &FieldValue) -> Result<(), String> {
+    let in_options =
+        |item: &str| field.options.is_empty() || field.options.iter().any(|o| o == item);
+    match (field.field_type, value) {
+        (FieldType::Text, FieldValue::Text(_)) => Ok(()),
+        (FieldType::CheckBox, FieldValue::Bool(_)) => Ok(()),
+        (FieldType::CheckBox, FieldValue::Text(v)) if v == "Off" || in_options(v) => Ok(()),
+        (FieldType::RadioButton, FieldValue::Text(v)) if v == "Off" || in_options(v) => Ok(()),
+        (FieldType::ComboBox, FieldValue::Text(v))
+            if field.flags.contains(FieldFlags::EDIT) || in_options(v) =>
+        {
+            Ok(())
+        }
+        (FieldType::ListBox, FieldValue::Text(v)) if in_options(v) => Ok(()),
+        (FieldType::ListBox, FieldValue::List(items))
+            if items.len() > 1 && !field.flags.contains(FieldFlags::MULTI_SELECT) =>
+        {
+            Err("field does not allow multiple selection".to_string())
+        }
+        (FieldType::ListBox, FieldValue::List(items))
+            if items.iter().all(|item| in_options(item)) =>
+        {
+            Ok(())
+        }
+        (FieldType::PushButton | FieldType::Signature, _) => {
+            Err(format!("{:?} fields cannot be filled", field.field_type))
+        }
+        (FieldType::RadioButton | FieldType::ComboBox | FieldType::ListBox, _)
+        | (FieldType::CheckBox, FieldValue::Text(_)) => Err(format!(
+            "value is not one of the field options: {}",
+            field.options.join(", ")
+        )),
+        (field_type, _) => Err(format!("value type does not match {:?} field", field_type)),
+    }
+}
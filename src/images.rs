@@ -0,0 +1,81 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::rect::Rect;
+
+/// ImageInfo describes an image placed on a page of the PDF-document.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ImageInfo {
+    /// The identifier of the image XObject, used by `Document::extract_image`.
+    /// An image placed on several pages has the same identifier on each of them.
+    #[serde(rename = "id")]
+    pub id: String,
+
+    /// The page number (1-based).
+    #[serde(rename = "page")]
+    pub page: i32,
+
+    /// The width in pixels.
+    #[serde(rename = "width")]
+    pub width: u32,
+
+    /// The height in pixels.
+    #[serde(rename = "height")]
+    pub height: u32,
+
+    #[serde(rename = "bitspercomponent")]
+    pub bits_per_component: u8,
+
+    /// The color space name (e.g. `"DeviceRGB"`, `"DeviceCMYK"`, `"ICCBased"`, `"Indexed"`).
+    #[serde(rename = "colorspace")]
+    pub color_space: String,
+
+    /// The filters of the image stream in decoding order (e.g. `["FlateDecode", "DCTDecode"]`),
+    /// empty if the stream is not compressed.
+    #[serde(rename = "filter", default, deserialize_with = "filters_from")]
+    pub filters: Vec<String>,
+
+    /// The effective horizontal resolution at the placed size.
+    #[serde(rename = "dpix")]
+    pub dpi_x: f64,
+
+    /// The effective vertical resolution at the placed size.
+    #[serde(rename = "dpiy")]
+    pub dpi_y: f64,
+
+    /// The placement rectangle on the page.
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+}
+
+impl ImageInfo {
+    /// Return the file extension of the image extracted with `ImageEncoding::Native`.
+    ///
+    /// The last filter encodes the image; the filters before it are decoded by the extraction.
+    pub fn native_extension(&self) -> &'static str {
+        match self
+            .filters
+            .last()
+            .map(|filter| filter.trim_start_matches('/'))
+        {
+            Some("DCTDecode") => "jpg",
+            Some("JPXDecode") => "jp2",
+            _ => "png",
+        }
+    }
+}
+
+// Accepts the filter of an image stream as a single name, an array of names or null.
+fn filters_from<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Filters {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<Filters>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(Filters::One(filter)) => vec![filter],
+        Some(Filters::Many(filters)) => filters,
+    })
+}
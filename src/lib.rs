@@ -1,22 +1,120 @@
+mod accessibility;
+mod annotation;
+mod attachment;
+mod bookmark;
+mod diff;
 mod document;
 mod document_gen_fn;
+mod einvoice;
 mod enums;
 mod errors;
 mod extern_c;
+mod flatten;
+mod form;
+mod images;
+mod merge;
+mod metadata;
+mod pdfa;
 mod permissions;
 mod product_info;
+mod rect;
+mod redaction;
+mod render;
+mod signature;
+mod structure;
+mod table;
+mod text_diff;
+mod tiles;
 mod utils;
+mod xfa;
 
+pub use accessibility::{AccessibilityCheck, AccessibilityIssue, AccessibilityReport};
+pub use annotation::{Annotation, AnnotationKind};
+pub use attachment::{AfRelationship, Attachment};
+pub use bookmark::{Bookmark, BookmarkStyle, Destination};
+pub use diff::{DiffOptions, PageDiff, VisualDiff};
 pub use document::Document;
-pub use enums::{CryptoAlgorithm, PageSize, Rotation};
+pub use einvoice::{EInvoice, FacturXProfile, FACTURX_NAMESPACE};
+pub use enums::{CryptoAlgorithm, ImageEncoding, ImageFormat, PageSize, PdfAFormat, Rotation};
 pub use errors::PdfError;
+pub use flatten::{FlattenOptions, FlattenTarget};
+pub use form::{
+    FieldDefinition, FieldFlags, FieldFont, FieldKind, FieldType, FieldValue, FormField,
+    ImportReport, InvalidValue, RadioButtonOption, Widget,
+};
+pub use images::ImageInfo;
+pub use merge::{FieldRename, FieldRenaming, MergeOptions};
+pub use metadata::{Metadata, PdfDate};
+pub use pdfa::{PdfAConversionReport, PdfAFix, ValidationReport, Violation};
 pub use permissions::Permissions;
 pub use product_info::ProductInfo;
+pub use rect::Rect;
+pub use redaction::{
+    RedactedContent, RedactedItem, RedactionAppearance, RedactionMark, RedactionPattern,
+    RedactionReport,
+};
+pub use render::{RenderOptions, RenderResolution, RgbaImage};
+#[cfg(feature = "core-preview")]
+pub use signature::SoftwareSigner;
+pub use signature::{
+    Certificate, DigestAlgorithm, SignatureFormat, SignatureInfo, SignatureOptions, Signer,
+    SigningKey, TrustStore, VerificationResult,
+};
+pub use structure::StructureElement;
+pub use table::{Cell, Table};
+pub use text_diff::{TextChange, TextDiff, TextSpan};
+pub use tiles::{Tile, TileOptions, TilePyramid};
+pub use xfa::{DroppedXfaField, XfaConversionReport};
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    // PKCS#12 with a self-signed RSA certificate (CN=Test Signer) and its private key (throwaway test key)
+    #[cfg(feature = "core-preview")]
+    const TEST_PFX: &[u8] = include_bytes!("../tests/data/test_signer.pfx");
+    #[cfg(feature = "core-preview")]
+    const TEST_PFX_PASSWORD: &str = "Test1234";
+
+    // The same certificate and its unencrypted PKCS#8 private key in PEM format (throwaway test key)
+    #[cfg(feature = "core-preview")]
+    const TEST_CERT_PEM: &str = include_str!("../tests/data/test_signer_cert.pem");
+    #[cfg(feature = "core-preview")]
+    const TEST_KEY_PEM: &str = include_str!("../tests/data/test_signer_key.pem");
+
+    // Builds a PDF-file from the bodies of its objects, numbered from 1; object 1 is the catalog
+    #[cfg(feature = "core-preview")]
+    fn pdf_from_objects(objects: &[Vec<u8>]) -> Vec<u8> {
+        let mut pdf = b"%PDF-1.7\n".to_vec();
+        let mut offsets = Vec::new();
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+            pdf.extend_from_slice(object);
+            pdf.extend_from_slice(b"\nendobj\n");
+        }
+        let xref = pdf.len();
+        let size = objects.len() + 1;
+        pdf.extend_from_slice(format!("xref\n0 {size}\n0000000000 65535 f \n").as_bytes());
+        for offset in offsets {
+            pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        pdf.extend_from_slice(
+            format!("trailer\n<< /Size {size} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n")
+                .as_bytes(),
+        );
+        pdf
+    }
+
+    // Returns the body of a stream object with the dictionary entries and the data
+    #[cfg(feature = "core-preview")]
+    fn pdf_stream(entries: &str, data: &[u8]) -> Vec<u8> {
+        let mut object = format!("<< {entries} /Length {} >>\nstream\n", data.len()).into_bytes();
+        object.extend_from_slice(data);
+        object.extend_from_slice(b"\nendstream");
+        object
+    }
+
     #[test]
     fn pdf_about() -> Result<(), Box<dyn std::error::Error>> {
         let pdf = Document::new()?;
@@ -953,4 +1051,1823 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_signatures() -> Result<(), Box<dyn std::error::Error>> {
+        let pdf = Document::new()?;
+        pdf.page_add()?;
+
+        let output_path = std::env::temp_dir().join("pdf_signatures.pdf");
+        let output_str = output_path.to_str().unwrap();
+        pdf.sign_pkcs7(
+            1,
+            TEST_PFX,
+            TEST_PFX_PASSWORD,
+            0,
+            0,
+            0,
+            0,
+            "Approved",
+            "Contact Info",
+            "Location Info",
+            false,
+            &[],
+            output_str,
+        )?;
+
+        let pdf_sign = Document::open(output_str)?;
+        let signatures = pdf_sign.signatures()?;
+        assert_eq!(signatures.len(), 1);
+        let signature = &signatures[0];
+        assert_eq!(signature.reason.as_deref(), Some("Approved"));
+        assert_eq!(signature.location.as_deref(), Some("Location Info"));
+        assert_eq!(signature.byte_range.len(), 4);
+        assert!(signature.covers_whole_document);
+        let signer = signature.signer().expect("signer certificate");
+        assert_eq!(signer.subject, "CN=Test Signer");
+        assert!(signer.is_self_signed());
+        assert!(!signer.der.is_empty());
+
+        // Intact, but not trusted until the signer certificate is added to the trust store
+        let result = pdf_sign.verify_signature(&signature.name, &TrustStore::new())?;
+        assert!(result.integrity);
+        assert!(!result.trusted);
+        assert!(!result.is_valid());
+
+        let trust_store = TrustStore::new().with_certificate(&signer.der);
+        let result = pdf_sign.verify_signature(&signature.name, &trust_store)?;
+        assert!(result.is_valid(), "{:?}", result.errors);
+
+        assert!(pdf_sign
+            .verify_signature("NoSuchSignature", &trust_store)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn test_sign_with_certificate() -> Result<(), Box<dyn std::error::Error>> {
+        let key = SigningKey::new(TEST_CERT_PEM.as_bytes(), TEST_KEY_PEM.as_bytes());
+        let trust_store = TrustStore::new().with_certificate(TEST_CERT_PEM.as_bytes());
+        let tmp_dir = std::env::temp_dir();
+
+        for (format, digest) in [
+            (SignatureFormat::Pkcs7, DigestAlgorithm::Sha256),
+            (SignatureFormat::Pkcs7Detached, DigestAlgorithm::Sha384),
+            (SignatureFormat::PadesBaseline, DigestAlgorithm::Sha512),
+        ] {
+            let pdf = Document::new()?;
+            pdf.page_add()?;
+
+            let output_path = tmp_dir.join(format!("sign_with_certificate_{:?}.pdf", format));
+            let output_str = output_path.to_str().unwrap();
+
+            let options = SignatureOptions::new()
+                .visible(1, Rect::new(100.0, 100.0, 300.0, 160.0))
+                .with_field_name("Approval")
+                .with_reason("Approved")
+                .with_format(format)
+                .with_digest(digest);
+            pdf.sign_with_certificate(&key, &options, output_str)?;
+
+            let pdf_sign = Document::open(output_str)?;
+            let signatures = pdf_sign.signatures()?;
+            assert_eq!(signatures.len(), 1);
+            assert_eq!(signatures[0].name, "Approval");
+            assert_eq!(
+                signatures[0].sub_filter.as_deref(),
+                Some(format.sub_filter())
+            );
+            assert_eq!(
+                signatures[0].signer().map(|c| c.subject.as_str()),
+                Some("CN=Test Signer")
+            );
+
+            let result = pdf_sign.verify_signature("Approval", &trust_store)?;
+            assert!(result.is_valid(), "{:?}: {:?}", format, result.errors);
+        }
+
+        // An invalid private key is rejected
+        let invalid_key = SigningKey::new(TEST_CERT_PEM.as_bytes(), b"not a key");
+        let pdf = Document::new()?;
+        pdf.page_add()?;
+        let output_path = tmp_dir.join("sign_with_certificate_invalid.pdf");
+        assert!(pdf
+            .sign_with_certificate(
+                &invalid_key,
+                &SignatureOptions::new(),
+                output_path.to_str().unwrap()
+            )
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn test_sign_external() -> Result<(), Box<dyn std::error::Error>> {
+        struct FailingSigner;
+
+        impl Signer for FailingSigner {
+            fn sign(
+                &self,
+                _digest: &[u8],
+                _algorithm: DigestAlgorithm,
+                _format: SignatureFormat,
+            ) -> Result<Vec<u8>, PdfError> {
+                Err(PdfError::CoreExceptionError(
+                    "device unavailable".to_string(),
+                ))
+            }
+        }
+
+        struct OversizedSigner;
+
+        impl Signer for OversizedSigner {
+            fn signature_size(&self) -> usize {
+                16
+            }
+
+            fn sign(
+                &self,
+                _digest: &[u8],
+                _algorithm: DigestAlgorithm,
+                _format: SignatureFormat,
+            ) -> Result<Vec<u8>, PdfError> {
+                Ok(vec![0; 17])
+            }
+        }
+
+        struct UnboundedSigner;
+
+        impl Signer for UnboundedSigner {
+            fn signature_size(&self) -> usize {
+                usize::MAX
+            }
+
+            fn sign(
+                &self,
+                _digest: &[u8],
+                _algorithm: DigestAlgorithm,
+                _format: SignatureFormat,
+            ) -> Result<Vec<u8>, PdfError> {
+                Ok(Vec::new())
+            }
+        }
+
+        let key = SigningKey::new(TEST_CERT_PEM.as_bytes(), TEST_KEY_PEM.as_bytes());
+        let signer = SoftwareSigner::new(key);
+        let trust_store = TrustStore::new().with_certificate(TEST_CERT_PEM.as_bytes());
+        let tmp_dir = std::env::temp_dir();
+
+        let pdf = Document::new()?;
+        pdf.page_add()?;
+
+        // PAdES baseline signature created by the software signer
+        let output_path = tmp_dir.join("sign_external.pdf");
+        let output_str = output_path.to_str().unwrap();
+        let options = SignatureOptions::pades()
+            .with_field_name("External")
+            .with_digest(DigestAlgorithm::Sha384);
+        pdf.sign_external(&signer, &options, output_str)?;
+
+        let pdf_sign = Document::open(output_str)?;
+        let signatures = pdf_sign.signatures()?;
+        assert_eq!(signatures.len(), 1);
+        assert_eq!(
+            signatures[0].sub_filter.as_deref(),
+            Some("ETSI.CAdES.detached")
+        );
+        assert!(signatures[0].covers_whole_document);
+        let result = pdf_sign.verify_signature("External", &trust_store)?;
+        assert!(result.is_valid(), "{:?}", result.errors);
+
+        // Signer errors, oversized CMS and unsupported sizes abort the signing without leaving a file
+        for failing in [
+            &FailingSigner as &dyn Signer,
+            &OversizedSigner,
+            &UnboundedSigner,
+        ] {
+            let output_path = tmp_dir.join("sign_external_failed.pdf");
+            let output_str = output_path.to_str().unwrap();
+            assert!(pdf
+                .sign_external(failing, &SignatureOptions::new(), output_str)
+                .is_err());
+            assert!(!output_path.exists());
+        }
+
+        // adbe.pkcs7.sha1 cannot be signed externally
+        let options = SignatureOptions::new().with_format(SignatureFormat::Pkcs7);
+        assert!(pdf.sign_external(&signer, &options, output_str).is_err());
+
+        // A file that the failing calls did not write is kept
+        let existing_path = tmp_dir.join("sign_external_existing.pdf");
+        let existing_str = existing_path.to_str().unwrap();
+        std::fs::write(&existing_path, b"existing")?;
+        assert!(pdf.sign_external(&signer, &options, existing_str).is_err());
+        let options = SignatureOptions {
+            page: 99,
+            ..SignatureOptions::new()
+        };
+        assert!(pdf.sign_external(&signer, &options, existing_str).is_err());
+        assert_eq!(std::fs::read(&existing_path)?, b"existing");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_redact() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "SSN: 123-45-6789, e-mail: john.doe@example.com")?;
+
+        let report = doc.redact(
+            &[
+                RedactionMark::pattern(RedactionPattern::Ssn),
+                RedactionMark::pattern(RedactionPattern::Email),
+            ],
+            &RedactionAppearance {
+                overlay_text: Some("REDACTED".to_string()),
+                ..Default::default()
+            },
+        )?;
+
+        // Both matches are reported on the first page
+        assert!(!report.is_empty(), "Expected redacted items");
+        assert!(report.items.iter().all(|item| item.page == 1));
+
+        // The underlying text is removed, not just covered
+        let text = doc.extract_text()?;
+        assert!(!text.contains("123-45-6789"), "SSN is still present");
+        assert!(
+            !text.contains("john.doe@example.com"),
+            "E-mail is still present"
+        );
+
+        // Redacting an empty area reports nothing
+        let empty_doc = Document::new()?;
+        empty_doc.page_add()?;
+        let report = empty_doc.redact(
+            &[RedactionMark::area(1, Rect::new(0.0, 0.0, 100.0, 100.0))],
+            &RedactionAppearance::default(),
+        )?;
+        assert!(
+            report.is_empty(),
+            "Expected no redacted items on blank page"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_metadata() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+
+        let metadata = Metadata {
+            title: Some("Annual Report".to_string()),
+            author: Some("Finance Department".to_string()),
+            subject: Some("Results".to_string()),
+            keywords: Some("report, finance".to_string()),
+            creation_date: Some(PdfDate::new(2024, 3, 15, 10, 30, 0)?.with_utc_offset(120)?),
+            ..Default::default()
+        };
+        doc.set_metadata(&metadata)?;
+
+        // Metadata is preserved across save and reopen
+        let path = format!("{}/metadata.pdf", std::env::temp_dir().display());
+        doc.save_as(&path)?;
+        let reopened = Document::open(&path)?;
+        let read = reopened.metadata()?;
+        assert_eq!(read.title, metadata.title);
+        assert_eq!(read.author, metadata.author);
+        assert_eq!(read.subject, metadata.subject);
+        assert_eq!(read.keywords, metadata.keywords);
+        assert_eq!(read.creation_date, metadata.creation_date);
+
+        // Custom XMP namespace
+        let ns = "http://example.com/archive/1.0/";
+        reopened.set_xmp_property(ns, "arc", "RecordId", "A-42")?;
+        assert_eq!(
+            reopened.xmp_property(ns, "RecordId")?,
+            Some("A-42".to_string())
+        );
+        assert!(reopened.xmp()?.contains("A-42"));
+        assert_eq!(reopened.xmp_property(ns, "Missing")?, None);
+
+        // Strip metadata
+        reopened.strip_metadata()?;
+        let stripped = reopened.metadata()?;
+        assert_eq!(stripped.title, None);
+        assert_eq!(stripped.author, None);
+        assert_eq!(reopened.xmp_property(ns, "RecordId")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pdf_date_format() -> Result<(), Box<dyn std::error::Error>> {
+        let date: PdfDate = "D:20240315103000+02'00'".parse()?;
+        assert_eq!(
+            date,
+            PdfDate::new(2024, 3, 15, 10, 30, 0)?.with_utc_offset(120)?
+        );
+        assert_eq!(date.to_string(), "D:20240315103000+02'00'");
+
+        // Optional fields default as in the PDF specification
+        let date: PdfDate = "D:2024".parse()?;
+        assert_eq!(date, PdfDate::new(2024, 1, 1, 0, 0, 0)?);
+        assert_eq!(date.to_string(), "D:20240101000000Z");
+
+        let date: PdfDate = "D:20240315103000-05'30".parse()?;
+        assert_eq!(date.utc_offset_minutes, -330);
+
+        assert!("D:20241315".parse::<PdfDate>().is_err());
+        assert!("not a date".parse::<PdfDate>().is_err());
+
+        // Days are checked against the month, including leap years
+        assert!("D:20240230".parse::<PdfDate>().is_err());
+        assert!("D:20230229".parse::<PdfDate>().is_err());
+        assert!("D:19000229".parse::<PdfDate>().is_err());
+        assert!("D:20240431".parse::<PdfDate>().is_err());
+        assert_eq!(
+            "D:20000229".parse::<PdfDate>()?,
+            PdfDate::new(2000, 2, 29, 0, 0, 0)?
+        );
+
+        // Offsets are limited to 23 hours and 59 minutes
+        assert!("D:2024+999".parse::<PdfDate>().is_err());
+        assert!("D:2024+24'00'".parse::<PdfDate>().is_err());
+        assert!("D:2024-05'60'".parse::<PdfDate>().is_err());
+        assert_eq!(
+            "D:2024-23'59'".parse::<PdfDate>()?.utc_offset_minutes,
+            -1439
+        );
+
+        // Constructors apply the same checks
+        assert!(PdfDate::new(2023, 2, 29, 0, 0, 0).is_err());
+        assert!(PdfDate::new(2024, 13, 1, 0, 0, 0).is_err());
+        assert!(PdfDate::new(2024, 1, 1, 24, 0, 0).is_err());
+        assert!(PdfDate::new(2024, 1, 1, 0, 0, 0)?
+            .with_utc_offset(1440)
+            .is_err());
+        assert!(PdfDate::new(2024, 1, 1, 0, 0, 0)?
+            .with_utc_offset(-1439)
+            .is_ok());
+
+        // A malformed date is read as absent instead of failing the whole object
+        let metadata: Metadata = serde_json::from_str(
+            r#"{"title": "Report", "creationdate": "D:20240230", "moddate": "D:20240315103000Z"}"#,
+        )?;
+        assert_eq!(metadata.title.as_deref(), Some("Report"));
+        assert_eq!(metadata.creation_date, None);
+        assert_eq!(
+            metadata.mod_date,
+            Some(PdfDate::new(2024, 3, 15, 10, 30, 0)?)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_bookmarks() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        for _ in 0..3 {
+            doc.page_add()?;
+        }
+
+        // A new PDF-document has no outline
+        assert!(doc.bookmarks()?.is_empty());
+
+        doc.set_bookmarks(&[
+            Bookmark::new("Chapter 1", 1).with_child(Bookmark::new("Section 1.1", 2)),
+            Bookmark::new("Chapter 2", 3).with_destination(Destination::Fit),
+        ])?;
+
+        let bookmarks = doc.bookmarks()?;
+        assert_eq!(bookmarks.len(), 2);
+        assert_eq!(bookmarks[0].title, "Chapter 1");
+        assert_eq!(bookmarks[0].children[0].title, "Section 1.1");
+        assert_eq!(bookmarks[0].children[0].page, 2);
+        assert_eq!(bookmarks[1].page, 3);
+
+        // Add, update, move and delete nodes
+        doc.add_bookmark(&[0], Bookmark::new("Section 1.2", 2))?;
+        doc.update_bookmark(
+            &[1],
+            Bookmark::new("Appendix", 3).with_style(BookmarkStyle {
+                bold: true,
+                ..Default::default()
+            }),
+        )?;
+        doc.move_bookmark(&[1], &[], 0)?;
+        let bookmarks = doc.bookmarks()?;
+        assert_eq!(bookmarks[0].title, "Appendix");
+        assert!(bookmarks[0].style.bold);
+        assert_eq!(bookmarks[1].children.len(), 2);
+
+        doc.delete_bookmark(&[1, 0])?;
+        assert_eq!(doc.bookmarks()?[1].children[0].title, "Section 1.2");
+        assert!(
+            doc.delete_bookmark(&[5]).is_err(),
+            "Expected invalid path error"
+        );
+
+        // JSON round trip
+        let path = format!("{}/bookmarks.json", std::env::temp_dir().display());
+        doc.export_bookmarks(&path)?;
+        let other = Document::new()?;
+        for _ in 0..3 {
+            other.page_add()?;
+        }
+        other.import_bookmarks(&path)?;
+        assert_eq!(other.bookmarks()?, doc.bookmarks()?);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_attachments() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        assert!(doc.attachments()?.is_empty());
+
+        let xml = b"<?xml version=\"1.0\"?><Invoice><ID>42</ID></Invoice>";
+        doc.add_attachment("invoice.xml", xml, "text/xml", "E-invoice")?;
+        doc.add_attachment("notes.txt", b"Supporting data", "text/plain", "Notes")?;
+
+        // Attachments survive save and reopen
+        let path = format!("{}/attachments.pdf", std::env::temp_dir().display());
+        doc.save_as(&path)?;
+        let reopened = Document::open(&path)?;
+
+        let attachments = reopened.attachments()?;
+        assert_eq!(attachments.len(), 2);
+        let invoice = attachments
+            .iter()
+            .find(|a| a.name == "invoice.xml")
+            .ok_or("invoice.xml not found")?;
+        assert_eq!(invoice.size, xml.len() as u64);
+        assert_eq!(invoice.mime_type.as_deref(), Some("text/xml"));
+        assert_eq!(invoice.description.as_deref(), Some("E-invoice"));
+
+        assert_eq!(reopened.extract_attachment("invoice.xml")?, xml.to_vec());
+        assert!(reopened.extract_attachment("missing.xml").is_err());
+
+        reopened.remove_attachment("notes.txt")?;
+        let attachments = reopened.attachments()?;
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].name, "invoice.xml");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_annotations() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add()?;
+        assert!(doc.annotations(1)?.is_empty());
+
+        let rect = Rect::new(100.0, 600.0, 300.0, 650.0);
+        let added = [
+            Annotation::text(rect, "Please review").with_author("Reviewer"),
+            Annotation::highlight(rect).with_color("#FFFF00"),
+            Annotation::link_page(rect, 2),
+            Annotation::stamp(rect, "Approved"),
+            Annotation::ink(rect, vec![vec![[110.0, 610.0], [200.0, 640.0]]]),
+            Annotation::free_text(rect, "Free text", 12.0),
+        ];
+        for (i, annotation) in added.iter().enumerate() {
+            assert_eq!(doc.add_annotation(1, annotation)?, i);
+        }
+
+        let annotations = doc.annotations(1)?;
+        assert_eq!(annotations.len(), added.len());
+        assert!(matches!(annotations[0].kind, AnnotationKind::Text { .. }));
+        assert_eq!(annotations[0].author.as_deref(), Some("Reviewer"));
+        assert_eq!(annotations[0].contents.as_deref(), Some("Please review"));
+        assert!(matches!(
+            annotations[1].kind,
+            AnnotationKind::Highlight { .. }
+        ));
+        assert!(matches!(
+            annotations[2].kind,
+            AnnotationKind::Link { page: Some(2), .. }
+        ));
+        assert!(matches!(annotations[3].kind, AnnotationKind::Stamp { .. }));
+        assert!(matches!(annotations[4].kind, AnnotationKind::Ink { .. }));
+        assert!(matches!(
+            annotations[5].kind,
+            AnnotationKind::FreeText { .. }
+        ));
+
+        // Modify and delete
+        let reply = annotations[0].clone().with_contents("Reviewed");
+        doc.update_annotation(1, 0, &reply)?;
+        assert_eq!(doc.annotations(1)?[0].contents.as_deref(), Some("Reviewed"));
+
+        assert!(doc.update_annotation(1, usize::MAX, &reply).is_err());
+        assert!(doc.delete_annotation(1, usize::MAX).is_err());
+        doc.delete_annotation(1, 5)?;
+        assert_eq!(doc.annotations(1)?.len(), added.len() - 1);
+        assert!(doc.annotations(2)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_form_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+
+        // A new PDF-document has no AcroForm fields
+        assert!(doc.form_fields()?.is_empty());
+
+        // Setting unknown fields fails
+        assert!(doc.set_field("applicant.name", "John Doe").is_err());
+
+        #[derive(serde::Serialize)]
+        struct Applicant {
+            name: String,
+            age: u32,
+        }
+        let applicant = Applicant {
+            name: "John Doe".to_string(),
+            age: 42,
+        };
+        assert!(doc.fill_form(&applicant, false).is_err());
+
+        // Only maps and structs can be used to fill a form
+        assert!(doc.fill_form(&"John Doe", false).is_err());
+
+        // A form with a text field, a check box and a choice
+        let rect = |y: f64| Rect::new(100.0, y, 300.0, y + 20.0);
+        doc.add_form_field(1, &FieldDefinition::text("name", rect(700.0)))?;
+        doc.add_form_field(1, &FieldDefinition::text("age", rect(650.0)))?;
+        doc.add_form_field(1, &FieldDefinition::checkbox("agree", rect(600.0)))?;
+        doc.add_form_field(
+            1,
+            &FieldDefinition::combo_box("country", rect(550.0), &["DE", "FR", "US"]),
+        )?;
+        let value = |doc: &Document, name: &str| -> Result<Option<FieldValue>, PdfError> {
+            Ok(doc
+                .form_fields()?
+                .into_iter()
+                .find(|field| field.name == name)
+                .and_then(|field| field.value))
+        };
+
+        // Set a single field of each kind
+        doc.set_field("name", "John Doe")?;
+        doc.set_field("agree", true)?;
+        doc.set_field("country", "FR")?;
+        assert_eq!(value(&doc, "name")?, Some(FieldValue::from("John Doe")));
+        assert_eq!(value(&doc, "agree")?, Some(FieldValue::from(true)));
+        assert_eq!(value(&doc, "country")?, Some(FieldValue::from("FR")));
+
+        // The values are saved with the PDF-document
+        let path = format!("{}/form_fields.pdf", std::env::temp_dir().display());
+        doc.save_as(&path)?;
+        let reopened = Document::open(&path)?;
+        assert_eq!(
+            value(&reopened, "name")?,
+            Some(FieldValue::from("John Doe"))
+        );
+        assert_eq!(value(&reopened, "agree")?, Some(FieldValue::from(true)));
+        assert_eq!(value(&reopened, "country")?, Some(FieldValue::from("FR")));
+
+        // Set several fields at once
+        let values = std::collections::HashMap::from([
+            ("name".to_string(), FieldValue::from("Jane Roe")),
+            ("agree".to_string(), FieldValue::from(false)),
+            ("country".to_string(), FieldValue::from("DE")),
+        ]);
+        doc.set_fields(&values)?;
+        for (name, expected) in &values {
+            assert_eq!(value(&doc, name)?.as_ref(), Some(expected));
+        }
+        assert!(doc.set_field("country", "IT").is_err());
+
+        // Fill from a struct, numbers become text
+        #[derive(serde::Serialize)]
+        struct Form {
+            name: String,
+            age: u32,
+            agree: bool,
+            country: &'static str,
+        }
+        doc.fill_form(
+            &Form {
+                name: "John Doe".to_string(),
+                age: 42,
+                agree: true,
+                country: "US",
+            },
+            false,
+        )?;
+        assert_eq!(value(&doc, "name")?, Some(FieldValue::from("John Doe")));
+        assert_eq!(value(&doc, "age")?, Some(FieldValue::from("42")));
+        assert_eq!(value(&doc, "agree")?, Some(FieldValue::from(true)));
+        assert_eq!(value(&doc, "country")?, Some(FieldValue::from("US")));
+
+        // Filling with no values succeeds and flattens the PDF-document
+        let empty: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+        doc.fill_form(&empty, true)?;
+        assert!(doc.form_fields()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_import_form_data() -> Result<(), Box<dyn std::error::Error>> {
+        let rect = |y: f64| Rect::new(100.0, y, 300.0, y + 20.0);
+        let form = || -> Result<Document, PdfError> {
+            let doc = Document::new()?;
+            doc.page_add()?;
+            doc.add_form_field(1, &FieldDefinition::text("name", rect(700.0)))?;
+            doc.add_form_field(1, &FieldDefinition::checkbox("agree", rect(650.0)))?;
+            doc.add_form_field(
+                1,
+                &FieldDefinition::combo_box("country", rect(600.0), &["DE", "FR", "US"]),
+            )?;
+            Ok(doc)
+        };
+        let values = |doc: &Document| -> Result<Vec<(String, Option<FieldValue>)>, PdfError> {
+            let mut values: Vec<_> = doc
+                .form_fields()?
+                .into_iter()
+                .map(|field| (field.name, field.value))
+                .collect();
+            values.sort_by(|a, b| a.0.cmp(&b.0));
+            Ok(values)
+        };
+
+        let filled = form()?;
+        filled.set_field("name", "John Doe")?;
+        filled.set_field("agree", true)?;
+        filled.set_field("country", "FR")?;
+        let expected = values(&filled)?;
+        let tmp_dir = std::env::temp_dir();
+
+        // Round trip with the existing exporters into an empty form
+        let fdf_path = format!("{}/import_form_data.fdf", tmp_dir.display());
+        filled.export_fdf(&fdf_path)?;
+        let doc = form()?;
+        let report = doc.import_fdf(&fdf_path)?;
+        assert!(report.is_complete());
+        assert_eq!(report.imported.len(), 3);
+        assert_eq!(values(&doc)?, expected);
+        let doc = form()?;
+        assert!(doc
+            .import_fdf_bytes(&std::fs::read(&fdf_path)?)?
+            .is_complete());
+        assert_eq!(values(&doc)?, expected);
+
+        let xfdf_path = format!("{}/import_form_data.xfdf", tmp_dir.display());
+        filled.export_xfdf(&xfdf_path)?;
+        let doc = form()?;
+        assert!(doc.import_xfdf(&xfdf_path)?.is_complete());
+        assert_eq!(values(&doc)?, expected);
+        let doc = form()?;
+        assert!(doc
+            .import_xfdf_bytes(&std::fs::read(&xfdf_path)?)?
+            .is_complete());
+        assert_eq!(values(&doc)?, expected);
+
+        let xml_path = format!("{}/import_form_data.xml", tmp_dir.display());
+        filled.export_xml(&xml_path)?;
+        let doc = form()?;
+        assert!(doc.import_xml(&xml_path)?.is_complete());
+        assert_eq!(values(&doc)?, expected);
+        let doc = form()?;
+        assert!(doc
+            .import_xml_bytes(&std::fs::read(&xml_path)?)?
+            .is_complete());
+        assert_eq!(values(&doc)?, expected);
+
+        // JSON values are validated; unknown fields and invalid values are reported, not failed
+        let doc = form()?;
+        let json_path = format!("{}/import_form_data.json", tmp_dir.display());
+        std::fs::write(
+            &json_path,
+            r#"{"name": "Jane Roe", "agree": true, "country": "IT", "address": {"city": "Berlin"}}"#,
+        )?;
+        let report = doc.import_json(&json_path)?;
+        assert!(!report.is_complete());
+        assert_eq!(report.imported, vec!["agree", "name"]);
+        assert_eq!(report.unknown_fields, vec!["address.city"]);
+        assert_eq!(report.invalid_values.len(), 1);
+        assert_eq!(report.invalid_values[0].field, "country");
+        assert_eq!(report.invalid_values[0].value, "IT");
+        assert!(report.invalid_values[0].reason.contains("DE, FR, US"));
+
+        // The valid values are imported, the invalid one leaves the field unchanged
+        let fields = doc.form_fields()?;
+        let value = |name: &str| {
+            fields
+                .iter()
+                .find(|f| f.name == name)
+                .unwrap()
+                .value
+                .clone()
+        };
+        assert_eq!(value("name"), Some(FieldValue::from("Jane Roe")));
+        assert_eq!(value("agree"), Some(FieldValue::from(true)));
+        assert_ne!(value("country"), Some(FieldValue::from("IT")));
+
+        // A value of the wrong type is invalid
+        let report = doc.import_json_bytes(br#"{"name": true}"#)?;
+        assert!(report.imported.is_empty());
+        assert_eq!(report.invalid_values[0].field, "name");
+
+        // Malformed JSON fails
+        assert!(doc.import_json_bytes(b"[1, 2, 3]").is_err());
+        assert!(doc.import_json_bytes(b"not json").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_add_form_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+
+        let rect = |y: f64| Rect::new(100.0, y, 300.0, y + 20.0);
+        let definitions = [
+            FieldDefinition::text("name", rect(700.0))
+                .required()
+                .with_tooltip("Full name")
+                .with_font(FieldFont::default()),
+            FieldDefinition::checkbox("agree", Rect::new(100.0, 660.0, 115.0, 675.0)),
+            FieldDefinition::radio_group(
+                "plan",
+                &[
+                    ("basic", Rect::new(100.0, 620.0, 115.0, 635.0)),
+                    ("premium", Rect::new(150.0, 620.0, 165.0, 635.0)),
+                ],
+            ),
+            FieldDefinition::combo_box("country", rect(580.0), &["DE", "FR", "US"])
+                .with_default_value("US"),
+            FieldDefinition::list_box("skills", rect(500.0), &["Rust", "C++", "Go"]),
+            FieldDefinition::push_button("submit", rect(460.0), "Submit"),
+            FieldDefinition::signature("signature", rect(400.0)).read_only(),
+        ];
+        for definition in &definitions {
+            doc.add_form_field(1, definition)?;
+        }
+
+        // Duplicate names are rejected
+        assert!(doc
+            .add_form_field(1, &FieldDefinition::text("name", rect(300.0)))
+            .is_err());
+
+        let fields = doc.form_fields()?;
+        assert_eq!(fields.len(), definitions.len());
+        let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+        assert_eq!(field("name").field_type, FieldType::Text);
+        assert!(field("name").flags.contains(FieldFlags::REQUIRED));
+        assert_eq!(field("name").widgets[0].page, 1);
+        assert_eq!(field("agree").field_type, FieldType::CheckBox);
+        assert_eq!(field("plan").field_type, FieldType::RadioButton);
+        assert_eq!(field("plan").widgets.len(), 2);
+        assert_eq!(field("country").field_type, FieldType::ComboBox);
+        assert_eq!(field("country").value, Some(FieldValue::from("US")));
+        assert_eq!(field("skills").options, vec!["Rust", "C++", "Go"]);
+        assert_eq!(field("submit").field_type, FieldType::PushButton);
+        assert!(field("signature").flags.contains(FieldFlags::READ_ONLY));
+
+        // Fill the new fields
+        doc.set_field("name", "John Doe")?;
+        doc.set_field("agree", true)?;
+        doc.set_field("plan", "premium")?;
+        let fields = doc.form_fields()?;
+        let field = |name: &str| fields.iter().find(|f| f.name == name).unwrap();
+        assert_eq!(field("name").value, Some(FieldValue::from("John Doe")));
+
+        // JSON import validates values against field types and options
+        let report = doc.import_json_bytes(
+            br#"{"country": "FR", "plan": "gold", "agree": "maybe", "skills": ["Rust", "Go"]}"#,
+        )?;
+        assert_eq!(report.imported, vec!["country"]);
+        let invalid: Vec<&str> = report
+            .invalid_values
+            .iter()
+            .map(|v| v.field.as_str())
+            .collect();
+        assert_eq!(invalid, vec!["agree", "plan", "skills"]);
+
+        // The new fields are exported by the existing exporters
+        let path = format!("{}/add_form_fields.xfdf", std::env::temp_dir().display());
+        doc.export_xfdf(&path)?;
+        let xfdf = std::fs::read_to_string(&path)?;
+        assert!(xfdf.contains("John Doe"));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_flatten_with_options() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add()?;
+        let rect = |y: f64| Rect::new(100.0, y, 300.0, y + 20.0);
+        doc.add_form_field(1, &FieldDefinition::text("approved_by", rect(700.0)))?;
+        doc.add_form_field(1, &FieldDefinition::text("comments", rect(650.0)))?;
+        doc.add_form_field(2, &FieldDefinition::text("notes", rect(700.0)))?;
+        doc.add_annotation(1, &Annotation::text(rect(600.0), "Check"))?;
+        doc.add_annotation(2, &Annotation::text(rect(600.0), "Check"))?;
+        doc.set_field("approved_by", "Jane Doe")?;
+
+        // Lock a signed-off field while keeping the rest fillable
+        doc.flatten_with_options(
+            &FlattenOptions::fields(&["approved_by"]).with_regenerated_appearances(),
+        )?;
+        let names: Vec<String> = doc.form_fields()?.into_iter().map(|f| f.name).collect();
+        assert_eq!(names, vec!["comments", "notes"]);
+        assert_eq!(doc.annotations(1)?.len(), 1);
+
+        // Flatten annotations on the second page only
+        doc.flatten_with_options(&FlattenOptions::annotations().with_pages(&[2]))?;
+        assert_eq!(doc.annotations(1)?.len(), 1);
+        assert!(doc.annotations(2)?.is_empty());
+        assert_eq!(doc.form_fields()?.len(), 2);
+
+        // Flatten the remaining form fields
+        doc.flatten_with_options(&FlattenOptions::form_fields())?;
+        assert!(doc.form_fields()?.is_empty());
+        assert_eq!(doc.annotations(1)?.len(), 1);
+
+        // Unknown field names are rejected
+        assert!(doc
+            .flatten_with_options(&FlattenOptions::fields(&["missing"]))
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_xfa() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.add_form_field(
+            1,
+            &FieldDefinition::text("name", Rect::new(100.0, 700.0, 300.0, 720.0)),
+        )?;
+        assert!(!doc.has_xfa()?);
+        assert_eq!(doc.xfa_data()?, None);
+        assert!(doc.convert_xfa_to_acroform().is_err());
+
+        // A dynamic XFA form with a text field and an image field, which has no AcroForm equivalent
+        let xdp = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xdp:xdp xmlns:xdp="http://ns.adobe.com/xdp/">
+<template xmlns="http://www.xfa.org/schema/xfa-template/3.3/">
+<subform name="form1" layout="tb" locale="en_US">
+<pageSet><pageArea name="Page1"><contentArea x="0.25in" y="0.25in" w="8in" h="10.5in"/>
+<medium stock="letter" short="8.5in" long="11in"/></pageArea></pageSet>
+<subform name="page" w="8in" h="10.5in">
+<field name="name" x="1in" y="1in" w="3in" h="0.3in"><ui><textEdit/></ui></field>
+<field name="photo" x="1in" y="2in" w="2in" h="2in"><ui><imageEdit/></ui></field>
+</subform>
+</subform>
+</template>
+<xfa:datasets xmlns:xfa="http://www.xfa.org/schema/xfa-data/1.0/">
+<xfa:data><form1><page><name>Alice</name></page></form1></xfa:data>
+</xfa:datasets>
+</xdp:xdp>"#;
+        let pdf = pdf_from_objects(&[
+            b"<< /Type /Catalog /Pages 2 0 R /NeedsRendering true \
+               /AcroForm << /Fields [] /XFA 4 0 R >> >>"
+                .to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] >>".to_vec(),
+            pdf_stream("", xdp.as_bytes()),
+        ]);
+        let doc = Document::open_bytes(&pdf)?;
+        assert!(doc.has_xfa()?);
+        let data = doc.xfa_data()?.unwrap();
+        assert!(data.contains(r#"<field name="photo""#));
+        assert!(data.contains("<name>Alice</name>"));
+
+        let report = doc.convert_xfa_to_acroform()?;
+        assert!(!report.is_lossless());
+        assert_eq!(report.converted_fields.len(), 1);
+        assert!(report.converted_fields[0].contains("name"));
+        assert_eq!(report.dropped_fields.len(), 1);
+        assert!(report.dropped_fields[0].name.contains("photo"));
+        assert!(!report.dropped_fields[0].reason.is_empty());
+
+        // The converted form is an AcroForm with the XFA data
+        assert!(!doc.has_xfa()?);
+        assert_eq!(doc.xfa_data()?, None);
+        let fields = doc.form_fields()?;
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].name, report.converted_fields[0]);
+        assert_eq!(fields[0].value, Some(FieldValue::from("Alice")));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_merge_documents_with_options() -> Result<(), Box<dyn std::error::Error>> {
+        let rect = |y: f64| Rect::new(100.0, y, 300.0, y + 20.0);
+        let form = |name: &str| -> Result<Document, PdfError> {
+            let doc = Document::new()?;
+            doc.page_add()?;
+            doc.add_form_field(1, &FieldDefinition::text("name", rect(700.0)))?;
+            doc.add_form_field(1, &FieldDefinition::text("id", rect(650.0)))?;
+            doc.set_field("name", name)?;
+            Ok(doc)
+        };
+        let pdf1 = form("Alice")?;
+        let pdf2 = form("Bob")?;
+        pdf2.rename_field("id", "employee_id")?;
+        assert!(pdf2.rename_field("missing", "other").is_err());
+
+        // Reloading from memory keeps the form
+        let copy = Document::open_bytes(&pdf2.bytes()?)?;
+        assert_eq!(copy.form_fields()?.len(), 2);
+
+        let (merged, renames) = Document::merge_documents_with_options(
+            &[&pdf1, &pdf2],
+            &MergeOptions::prefix()
+                .with_labels(&["a", "b"])
+                .only_conflicting(),
+        )?;
+        assert_eq!(merged.page_count()?, 2);
+        assert_eq!(
+            renames,
+            vec![
+                FieldRename {
+                    document: 0,
+                    old_name: "name".to_string(),
+                    new_name: "a_name".to_string(),
+                },
+                FieldRename {
+                    document: 1,
+                    old_name: "name".to_string(),
+                    new_name: "b_name".to_string(),
+                },
+            ]
+        );
+
+        // The merged packets keep their independent values
+        let fields = merged.form_fields()?;
+        let value = |name: &str| {
+            fields
+                .iter()
+                .find(|f| f.name == name)
+                .unwrap()
+                .value
+                .clone()
+        };
+        assert_eq!(value("a_name"), Some(FieldValue::from("Alice")));
+        assert_eq!(value("b_name"), Some(FieldValue::from("Bob")));
+        assert!(fields.iter().any(|f| f.name == "id"));
+        assert!(fields.iter().any(|f| f.name == "employee_id"));
+
+        // The source PDF-documents are not changed
+        assert!(pdf1.form_fields()?.iter().any(|f| f.name == "name"));
+
+        // Suffix every field
+        let (_, renames) =
+            Document::merge_documents_with_options(&[&pdf1, &pdf2], &MergeOptions::suffix())?;
+        assert_eq!(renames.len(), 4);
+        assert_eq!(renames[0].new_name, "name_doc1");
+
+        // Labels must match the source PDF-documents
+        assert!(Document::merge_documents_with_options(
+            &[&pdf1, &pdf2],
+            &MergeOptions::prefix().with_labels(&["a"]),
+        )
+        .is_err());
+
+        // Append a form to a PDF-document, renaming the fields of both
+        let target = form("Carol")?;
+        let renames =
+            target.append_with_options(&pdf2, &MergeOptions::suffix().only_conflicting())?;
+        assert_eq!(renames.len(), 2);
+        assert_eq!(target.page_count()?, 2);
+        let fields = target.form_fields()?;
+        let value = |name: &str| {
+            fields
+                .iter()
+                .find(|f| f.name == name)
+                .unwrap()
+                .value
+                .clone()
+        };
+        assert_eq!(value("name_doc1"), Some(FieldValue::from("Carol")));
+        assert_eq!(value("name_doc2"), Some(FieldValue::from("Bob")));
+        assert!(pdf2.form_fields()?.iter().any(|f| f.name == "name"));
+
+        // A new name used by another field gets a numeric suffix instead of failing
+        pdf2.rename_field("employee_id", "a_name")?;
+        let (merged, renames) = Document::merge_documents_with_options(
+            &[&pdf1, &pdf2],
+            &MergeOptions::prefix()
+                .with_labels(&["a", "b"])
+                .only_conflicting(),
+        )?;
+        assert_eq!(renames[0].new_name, "a_name_2");
+        let names: Vec<String> = merged.form_fields()?.into_iter().map(|f| f.name).collect();
+        assert!(["a_name", "a_name_2", "b_name"]
+            .iter()
+            .all(|name| names.iter().any(|n| n == name)));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn merge_field_renames() {
+        let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        let field_names: Vec<Vec<String>> = vec![
+            names(&["name", "id"]),
+            names(&["name", "doc1_name"]),
+            names(&["doc1_name_2"]),
+        ];
+
+        let renames =
+            merge::field_renames(&field_names, &MergeOptions::prefix().only_conflicting());
+        let new_names = |renames: &[FieldRename]| -> Vec<String> {
+            renames
+                .iter()
+                .map(|rename| rename.new_name.clone())
+                .collect()
+        };
+        // "doc1_name" and "doc1_name_2" are taken by other fields
+        assert_eq!(new_names(&renames[0]), vec!["doc1_name_3"]);
+        assert_eq!(new_names(&renames[1]), vec!["doc2_name"]);
+        assert!(renames[2].is_empty());
+
+        let renames = merge::field_renames(&field_names, &MergeOptions::suffix());
+        assert_eq!(renames.concat().len(), field_names.iter().flatten().count());
+        assert!(merge::field_renames(&field_names, &MergeOptions::default())
+            .iter()
+            .all(|renames| renames.is_empty()));
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_images() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        assert!(doc.images(1)?.is_empty());
+        assert!(doc.images(2).is_err());
+        assert!(doc.extract_image("missing", ImageEncoding::Png).is_err());
+
+        // An 8x8 gray baseline JPEG with single Huffman codes for a zero DC difference and end of block
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xDB, 0x00, 0x43, 0x00];
+        jpeg.extend([1; 64]);
+        jpeg.extend([0xFF, 0xC0, 0x00, 0x0B, 8, 0, 8, 0, 8, 1, 1, 0x11, 0]);
+        for class in [0x00, 0x10] {
+            jpeg.extend([0xFF, 0xC4, 0x00, 0x14, class, 1]);
+            jpeg.extend([0; 16]);
+        }
+        jpeg.extend([
+            0xFF, 0xDA, 0x00, 0x08, 1, 1, 0, 0, 0x3F, 0, 0x3F, 0xFF, 0xD9,
+        ]);
+        let jpeg_hex: String = jpeg.iter().map(|b| format!("{b:02X}")).collect();
+
+        // The JPEG behind a filter array, and 2x1 uncompressed RGB pixels
+        let content = "q 80 0 0 60 100 600 cm /Im1 Do Q q 20 0 0 10 100 500 cm /Im2 Do Q";
+        let pdf = pdf_from_objects(&[
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            b"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] /Contents 4 0 R \
+               /Resources << /XObject << /Im1 5 0 R /Im2 6 0 R >> >> >>"
+                .to_vec(),
+            pdf_stream("", content.as_bytes()),
+            pdf_stream(
+                "/Type /XObject /Subtype /Image /Width 8 /Height 8 /BitsPerComponent 8 \
+                 /ColorSpace /DeviceGray /Filter [/ASCIIHexDecode /DCTDecode]",
+                format!("{jpeg_hex}>").as_bytes(),
+            ),
+            pdf_stream(
+                "/Type /XObject /Subtype /Image /Width 2 /Height 1 /BitsPerComponent 8 \
+                 /ColorSpace /DeviceRGB",
+                &[255, 0, 0, 0, 0, 255],
+            ),
+        ]);
+        let doc = Document::open_bytes(&pdf)?;
+        let images = doc.images(1)?;
+        assert_eq!(images.len(), 2);
+
+        let photo = images.iter().find(|image| image.width == 8).unwrap();
+        assert_eq!((photo.height, photo.bits_per_component), (8, 8));
+        assert_eq!(photo.color_space, "DeviceGray");
+        assert_eq!(photo.filters, vec!["ASCIIHexDecode", "DCTDecode"]);
+        assert_eq!(photo.native_extension(), "jpg");
+        assert_eq!(photo.rect, Rect::new(100.0, 600.0, 180.0, 660.0));
+        assert!((photo.dpi_x - 7.2).abs() < 0.01);
+        assert_eq!(doc.extract_image(&photo.id, ImageEncoding::Native)?, jpeg);
+
+        let pixels = images.iter().find(|image| image.width == 2).unwrap();
+        assert_eq!(pixels.color_space, "DeviceRGB");
+        assert!(pixels.filters.is_empty());
+        assert_eq!(pixels.native_extension(), "png");
+        let png = doc.extract_image(&pixels.id, ImageEncoding::Native)?;
+        assert!(png.starts_with(&[0x89, b'P', b'N', b'G']));
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_filters() -> Result<(), Box<dyn std::error::Error>> {
+        let image = |filter: &str| -> serde_json::Result<ImageInfo> {
+            serde_json::from_str(&format!(
+                r#"{{"id": "Im1", "page": 1, "width": 800, "height": 600, "bitspercomponent": 8,
+                    "colorspace": "DeviceRGB", "filter": {filter}, "dpix": 150.0, "dpiy": 150.0,
+                    "rect": {{"llx": 0.0, "lly": 0.0, "urx": 384.0, "ury": 288.0}}}}"#
+            ))
+        };
+        assert_eq!(image(r#""DCTDecode""#)?.native_extension(), "jpg");
+        assert_eq!(
+            image(r#"["FlateDecode", "DCTDecode"]"#)?.native_extension(),
+            "jpg"
+        );
+        assert_eq!(image(r#"["/JPXDecode"]"#)?.native_extension(), "jp2");
+        assert_eq!(
+            image(r#"["DCTDecode", "FlateDecode"]"#)?.native_extension(),
+            "png"
+        );
+        assert!(image("null")?.filters.is_empty());
+        assert_eq!(image("null")?.rect.width(), 384.0);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_extract_tables() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "No tables here")?;
+        assert!(doc.extract_tables("1-")?.is_empty());
+        assert!(doc.extract_tables("5").is_err());
+
+        // Spans are resolved to a regular grid
+        let cell = |text: &str, row_span: usize, col_span: usize| Cell {
+            text: text.to_string(),
+            rect: Rect::default(),
+            row_span,
+            col_span,
+        };
+        let table = Table {
+            page: 1,
+            rect: Rect::default(),
+            rows: vec![
+                vec![cell("Item", 2, 1), cell("2024", 1, 2)],
+                vec![cell("Q1", 1, 1), cell("Q2", 1, 1)],
+                vec![
+                    cell("Revenue, net", 1, 1),
+                    cell("1,000", 1, 1),
+                    cell("say \"hi\"", 1, 1),
+                ],
+            ],
+        };
+        assert_eq!(
+            table.to_csv(),
+            "Item,2024,\n,Q1,Q2\n\"Revenue, net\",\"1,000\",\"say \"\"hi\"\"\"\n"
+        );
+
+        let json = table.to_json()?;
+        let parsed: Table = serde_json::from_str(&json)?;
+        assert_eq!(parsed, table);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_render_page() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "Hello")?;
+
+        // Fit the page within 200x200 pixels
+        let image = doc.render_page(1, &RenderOptions::size(200, 200))?;
+        assert_eq!(image.width.max(image.height), 200);
+        assert!(image.stride >= image.width as usize * 4);
+        assert_eq!(image.data.len(), image.stride * image.height as usize);
+        assert_eq!(image.pixel(0, 0), [255, 255, 255, 255]);
+
+        // Clip a region of 1x1 inch at 72 DPI with a transparent background
+        let image = doc.render_page(
+            1,
+            &RenderOptions::dpi(72.0)
+                .with_transparent_background()
+                .with_clip(Rect::new(0.0, 0.0, 72.0, 72.0)),
+        )?;
+        assert_eq!((image.width, image.height), (72, 72));
+        assert_eq!(image.pixel(0, 0)[3], 0);
+        assert_eq!(image.rows().count(), 72);
+
+        assert!(doc.render_page(2, &RenderOptions::default()).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn rgba_image_to_dynamic_image() {
+        // 2x2 pixels with 4 bytes of padding per row
+        let rgba = RgbaImage {
+            width: 2,
+            height: 2,
+            stride: 12,
+            data: vec![
+                255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, //
+                0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0,
+            ],
+        };
+        let image = image::DynamicImage::try_from(rgba.clone())
+            .unwrap()
+            .into_rgba8();
+        assert_eq!(image.dimensions(), (2, 2));
+        assert_eq!(image.get_pixel(1, 0).0, [0, 255, 0, 255]);
+        assert_eq!(image.get_pixel(0, 1).0, [0, 0, 255, 255]);
+
+        // The last row is short
+        let short = RgbaImage {
+            data: rgba.data[..19].to_vec(),
+            ..rgba
+        };
+        assert!(image::DynamicImage::try_from(short).is_err());
+    }
+
+    #[test]
+    fn rgba_image_rows() {
+        // 2x2 pixels with 4 bytes of padding per row, the padding of the last row omitted
+        let rgba = RgbaImage {
+            width: 2,
+            height: 2,
+            stride: 12,
+            data: (0..20).collect(),
+        };
+        assert!(rgba.is_valid());
+        assert_eq!(
+            rgba.rows().collect::<Vec<_>>(),
+            vec![
+                &[0, 1, 2, 3, 4, 5, 6, 7][..],
+                &[12, 13, 14, 15, 16, 17, 18, 19][..]
+            ]
+        );
+
+        // A stride shorter than a row and a short last row yield no rows
+        let narrow = RgbaImage {
+            stride: 4,
+            ..rgba.clone()
+        };
+        assert!(!narrow.is_valid());
+        assert_eq!(narrow.rows().count(), 0);
+        let short = RgbaImage {
+            data: rgba.data[..19].to_vec(),
+            ..rgba
+        };
+        assert!(!short.is_valid());
+        assert_eq!(short.rows().count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_thumbnails() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        for num in 1..=5 {
+            doc.page_add()?;
+            doc.page_add_text(num, &format!("Page {num}"))?;
+        }
+
+        let png_signature = [0x89, b'P', b'N', b'G'];
+        let thumbnail = doc.page_thumbnail(1, 120, 160, ImageFormat::Png)?;
+        assert!(thumbnail.starts_with(&png_signature));
+
+        // All pages, in page order
+        let thumbnails = doc.thumbnails(120, 160, ImageFormat::Png)?;
+        assert_eq!(thumbnails.len(), 5);
+        assert_eq!(thumbnails[0], thumbnail);
+        assert!(thumbnails.iter().all(|t| t.starts_with(&png_signature)));
+
+        // Selected pages, in the listed order
+        let thumbnails = doc.thumbnails_range("4-,1", 120, 160, ImageFormat::Jpg)?;
+        assert_eq!(thumbnails.len(), 3);
+        assert!(thumbnails.iter().all(|t| t.starts_with(&[0xFF, 0xD8])));
+        assert_eq!(
+            thumbnails[2],
+            doc.page_thumbnail(1, 120, 160, ImageFormat::Jpg)?
+        );
+
+        assert!(doc
+            .thumbnails_range("4-9", 120, 160, ImageFormat::Png)
+            .is_err());
+        assert!(doc
+            .page_thumbnail(1, u32::MAX, 160, ImageFormat::Png)
+            .is_err());
+
+        // A PDF-document without pages has no thumbnails
+        let empty = Document::new()?;
+        assert!(empty.thumbnails(120, 160, ImageFormat::Png)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn page_range_parsing() {
+        assert_eq!(
+            utils::parse_page_range("-2,4,6-8,10-", 11).unwrap(),
+            vec![1, 2, 4, 6, 7, 8, 10, 11]
+        );
+        assert_eq!(utils::parse_page_range("1-", 3).unwrap(), vec![1, 2, 3]);
+        assert_eq!(utils::parse_page_range(" 3 , 1 ", 3).unwrap(), vec![3, 1]);
+        assert!(utils::parse_page_range("0", 3).is_err());
+        assert!(utils::parse_page_range("2-4", 3).is_err());
+        assert!(utils::parse_page_range("3-1", 3).is_err());
+        assert!(utils::parse_page_range("1,,2", 3).is_err());
+        assert!(utils::parse_page_range("a", 3).is_err());
+
+        // An empty string selects all pages, none of a PDF-document without pages
+        assert_eq!(utils::parse_page_range("", 3).unwrap(), vec![1, 2, 3]);
+        assert_eq!(utils::parse_page_range(" ", 0).unwrap(), Vec::<i32>::new());
+        assert!(utils::parse_page_range("1-", 0).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_render_region_and_tiles() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_set_size(1, PageSize::A4)?;
+        doc.page_add_text(1, "Tiles")?;
+
+        let page_rect = doc.page_rect(1)?;
+        assert_eq!(page_rect.width().round(), 595.0);
+        assert_eq!(page_rect.height().round(), 842.0);
+
+        let region = doc.render_region(
+            1,
+            &Rect::new(0.0, 742.0, 100.0, 842.0),
+            2.0,
+            ImageFormat::Png,
+        )?;
+        assert!(region.starts_with(&[0x89, b'P', b'N', b'G']));
+        assert!(doc
+            .render_region(1, &page_rect, 0.0, ImageFormat::Png)
+            .is_err());
+
+        let options = TileOptions::default();
+        let mut tiles = Vec::new();
+        let pyramid = doc.page_tiles(1, &options, |tile| {
+            tiles.push((tile.level, tile.column, tile.row));
+            Ok(())
+        })?;
+        assert_eq!((pyramid.width, pyramid.height), (595, 842));
+        assert_eq!(pyramid.max_level(), 10);
+        let expected: u32 = (0..=pyramid.max_level())
+            .map(|level| {
+                let (columns, rows) = pyramid.tile_count(level);
+                columns * rows
+            })
+            .sum();
+        assert_eq!(tiles.len(), expected as usize);
+        assert!(tiles.contains(&(10, 2, 3)));
+
+        let dir = format!("{}/page_tiles", std::env::temp_dir().display());
+        doc.save_page_tiles(1, &options, &dir, "page1")?;
+        assert!(std::path::Path::new(&format!("{dir}/page1.dzi")).exists());
+        assert!(std::path::Path::new(&format!("{dir}/page1_files/10/2_3.png")).exists());
+        assert!(std::path::Path::new(&format!("{dir}/page1_files/0/0_0.png")).exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tile_pyramid_layout() {
+        let pyramid = TilePyramid {
+            width: 595,
+            height: 842,
+            tile_size: 256,
+            overlap: 1,
+            format: ImageFormat::Jpg,
+        };
+        assert_eq!(pyramid.max_level(), 10);
+        assert_eq!(pyramid.level_size(10), (595, 842));
+        assert_eq!(pyramid.level_size(9), (298, 421));
+        assert_eq!(pyramid.level_size(0), (1, 1));
+        assert_eq!(pyramid.tile_count(10), (3, 4));
+        assert_eq!(pyramid.tile_count(0), (1, 1));
+        assert_eq!(pyramid.tile_bounds(10, 0, 0), (0, 0, 257, 257));
+        assert_eq!(pyramid.tile_bounds(10, 1, 3), (255, 767, 513, 842));
+        assert_eq!(pyramid.tile_bounds(10, 2, 0), (511, 0, 595, 257));
+        assert!(pyramid
+            .to_dzi()
+            .contains(r#"Format="jpg" Overlap="1" TileSize="256""#));
+        assert!(pyramid
+            .to_dzi()
+            .contains(r#"<Size Width="595" Height="842"/>"#));
+
+        // Out of range tiles and a zero tile size do not overflow or divide by zero
+        assert_eq!(pyramid.tile_bounds(10, u32::MAX, 0), (595, 0, 595, 257));
+        let empty = TilePyramid {
+            tile_size: 0,
+            ..pyramid
+        };
+        assert_eq!(empty.tile_count(10), (0, 0));
+        assert_eq!(empty.tile_bounds(10, 1, 1), (0, 0, 1, 1));
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn tile_pyramid_regions() {
+        let pyramid = TilePyramid {
+            width: 595,
+            height: 842,
+            tile_size: 256,
+            overlap: 1,
+            format: ImageFormat::Jpg,
+        };
+
+        // The tile regions of the most detailed level cover the page at the requested zoom
+        let page_rect = Rect::new(0.0, 0.0, 595.0, 842.0);
+        let (region, zoom) = pyramid.tile_region(&page_rect, 1.0, 10, 0, 0);
+        assert_eq!(zoom, 1.0);
+        assert_eq!(region, Rect::new(0.0, 585.0, 257.0, 842.0));
+        let (region, zoom) = pyramid.tile_region(&page_rect, 1.0, 9, 0, 0);
+        assert_eq!(zoom, 0.5);
+        assert_eq!(region, Rect::new(0.0, 328.0, 514.0, 842.0));
+
+        // The rounded-up levels below the most detailed one do not extend beyond the page
+        let (region, _) = pyramid.tile_region(&page_rect, 1.0, 9, 1, 0);
+        assert_eq!(region, Rect::new(510.0, 328.0, 595.0, 842.0));
+        let (region, _) = pyramid.tile_region(&page_rect, 1.0, 0, 0, 0);
+        assert_eq!(region, page_rect);
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_visual_diff() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "Invoice 2024-001")?;
+        doc.page_add()?;
+
+        // An unchanged copy matches
+        let copy = Document::open_bytes(&doc.bytes()?)?;
+        let diff = Document::visual_diff(&doc, &copy, &DiffOptions::default())?;
+        assert!(diff.is_match());
+        assert_eq!(diff.pages.len(), 2);
+        assert!(diff.pages.iter().all(|page| page.changed_area.is_none()));
+
+        // A visible change is detected on its page only
+        copy.page_add_text(2, "Changed")?;
+        let options = DiffOptions {
+            diff_images: true,
+            ..Default::default()
+        };
+        let diff = Document::visual_diff(&doc, &copy, &options)?;
+        assert!(!diff.is_match());
+        let changed: Vec<i32> = diff.changed_pages().map(|page| page.page).collect();
+        assert_eq!(changed, vec![2]);
+        assert!(diff.pages[1].diff_image.is_some());
+        assert!(diff.pages[0].diff_image.is_none());
+
+        // Missing pages count as fully changed
+        copy.page_add()?;
+        let diff = Document::visual_diff(&doc, &copy, &DiffOptions::default())?;
+        assert_eq!(diff.page_counts, (2, 3));
+        let missing = &diff.pages[2];
+        assert_eq!(missing.score, 1.0);
+        let (left, top, width, height) = missing.changed_area.unwrap();
+        assert_eq!((left, top), (0, 0));
+        assert_eq!(missing.changed_pixels, width as u64 * height as u64);
+        assert!(!diff.is_match());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn visual_diff_pixels() {
+        let image = |pixels: &[[u8; 4]], width: u32| RgbaImage {
+            width,
+            height: pixels.len() as u32 / width,
+            stride: width as usize * 4,
+            data: pixels.concat(),
+        };
+        let white = [255, 255, 255, 255];
+        let a = image(&[white, white, white, white], 2);
+        let b = image(&[white, [250, 250, 250, 255], white, [0, 0, 0, 255]], 2);
+
+        let options = DiffOptions {
+            diff_images: true,
+            ..Default::default()
+        };
+        let diff = diff::compare_images(1, &a, &b, &options);
+        assert_eq!(diff.changed_pixels, 1);
+        assert_eq!(diff.score, 0.25);
+        assert_eq!(diff.changed_area, Some((1, 1, 2, 2)));
+        let diff_image = diff.diff_image.unwrap();
+        assert_eq!(diff_image.pixel(1, 1), options.highlight_color);
+        assert_eq!(diff_image.pixel(0, 0), white);
+
+        // Without tolerance the anti-aliasing noise counts
+        let strict = DiffOptions {
+            tolerance: 0,
+            ..Default::default()
+        };
+        assert_eq!(diff::compare_images(1, &a, &b, &strict).changed_pixels, 2);
+
+        // Size differences count as changed pixels
+        let wide = image(&[white, white, white, white, white, white], 3);
+        let diff = diff::compare_images(1, &a, &wide, &strict);
+        assert_eq!(diff.changed_pixels, 2);
+        assert_eq!(diff.changed_area, Some((2, 0, 3, 2)));
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_compare_text() -> Result<(), Box<dyn std::error::Error>> {
+        let original = Document::new()?;
+        original.page_add()?;
+        original.page_add_text(1, "The quick brown fox jumps over the lazy dog")?;
+        original.page_add()?;
+        original.page_add_text(2, "Payment is due within 30 days")?;
+
+        let revised = Document::open_bytes(&original.bytes()?)?;
+        assert!(Document::compare_text(&original, &revised)?.is_empty());
+
+        revised.page_replace_text(1, "brown", "red")?;
+        revised.page_add_text(2, "Late fees apply")?;
+        let diff = Document::compare_text(&original, &revised)?;
+        assert!(!diff.is_empty());
+
+        let (old, new) = diff.modifications().next().unwrap();
+        assert_eq!((old.text.as_str(), new.text.as_str()), ("brown", "red"));
+        assert_eq!((old.page, new.page), (1, 1));
+        assert!(diff
+            .insertions()
+            .any(|span| span.page == 2 && span.text.contains("Late fees")));
+        assert_eq!(diff.deletions().count(), 0);
+
+        // Save the annotated comparison
+        let path = format!("{}/compare_text.pdf", std::env::temp_dir().display());
+        revised.save_text_comparison(&diff, &path)?;
+        let comparison = Document::open(&path)?;
+        let annotations = comparison.annotations(1)?.len() + comparison.annotations(2)?.len();
+        assert_eq!(annotations, diff.changes.len());
+        // The revised PDF-document is not changed
+        assert!(revised.annotations(1)?.is_empty());
+
+        // Deletions from a removed page are marked on the remaining page
+        let shortened = Document::open_bytes(&original.bytes()?)?;
+        shortened.page_delete(2)?;
+        let diff = Document::compare_text(&original, &shortened)?;
+        assert!(diff.deletions().all(|span| span.page == 2));
+        shortened.save_text_comparison(&diff, &path)?;
+        let comparison = Document::open(&path)?;
+        assert_eq!(comparison.page_count()?, 1);
+        assert_eq!(comparison.annotations(1)?.len(), diff.changes.len());
+
+        // A PDF-document without pages cannot show the changes
+        let empty = Document::new()?;
+        let diff = Document::compare_text(&original, &empty)?;
+        assert!(diff.deletions().count() > 0);
+        assert!(empty.save_text_comparison(&diff, &path).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_pdfa() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "Archived")?;
+        doc.add_annotation(
+            1,
+            &Annotation::link_uri(Rect::new(100.0, 700.0, 200.0, 720.0), "https://example.com"),
+        )?;
+
+        // A new PDF-document lacks the PDF/A identification and output intent
+        let report = doc.validate_pdfa(PdfAFormat::A2b)?;
+        assert!(!report.is_compliant());
+        assert!(report.violations.iter().all(|v| !v.clause.is_empty()));
+
+        let conversion = doc.convert_to_pdfa(PdfAFormat::A2b)?;
+        assert!(conversion.is_compliant());
+        assert!(!conversion.fixes.is_empty());
+        assert!(doc.validate_pdfa(PdfAFormat::A2b)?.is_compliant());
+        assert_eq!(
+            doc.xmp_property("http://www.aiim.org/pdfa/ns/id/", "part")?,
+            Some("2".to_string())
+        );
+
+        // A lossless conversion of the same content to PDF/A-3b
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "Archived")?;
+        let conversion = doc.convert_to_pdfa(PdfAFormat::A3b)?;
+        assert_eq!(conversion.lossy_fixes().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_pdfua() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "Accessible document")?;
+        assert_eq!(doc.language()?, None);
+
+        // An untagged PDF-document without language and title
+        let report = doc.validate_pdfua()?;
+        assert!(!report.is_compliant());
+        assert!(report.issues_of(AccessibilityCheck::MissingTags).count() > 0);
+        assert!(
+            report
+                .issues_of(AccessibilityCheck::MissingLanguage)
+                .count()
+                > 0
+        );
+        assert!(report.issues_of(AccessibilityCheck::TitleDisplay).count() > 0);
+
+        doc.auto_tag()?;
+        doc.set_language("en-US")?;
+        doc.set_metadata(&Metadata {
+            title: Some("Accessible document".to_string()),
+            ..Default::default()
+        })?;
+        assert_eq!(doc.language()?, Some("en-US".to_string()));
+
+        let report = doc.validate_pdfua()?;
+        assert_eq!(report.issues_of(AccessibilityCheck::MissingTags).count(), 0);
+        assert_eq!(
+            report
+                .issues_of(AccessibilityCheck::MissingLanguage)
+                .count(),
+            0
+        );
+
+        assert!(doc.set_image_alt_text("missing", "Logo").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_structure_tree() -> Result<(), Box<dyn std::error::Error>> {
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "Annual report")?;
+        assert!(doc.structure_tree()?.is_empty());
+
+        doc.auto_tag()?;
+        let tree = doc.structure_tree()?;
+        assert!(!tree.is_empty());
+        let paragraph = tree
+            .iter()
+            .flat_map(|element| element.descendants())
+            .find(|element| {
+                element
+                    .text
+                    .as_deref()
+                    .is_some_and(|text| text.contains("Annual report"))
+            })
+            .unwrap()
+            .clone();
+        assert_eq!(paragraph.role, "P");
+        assert_eq!(paragraph.pages, vec![1]);
+        assert_ne!(paragraph.id, tree[0].id);
+
+        // Promote the paragraph to a heading and describe it
+        doc.set_structure_role(&paragraph.id, "H1")?;
+        doc.set_structure_alt_text(&paragraph.id, "Title of the report")?;
+        doc.set_structure_actual_text(&paragraph.id, "Annual Report")?;
+        let tree = doc.structure_tree()?;
+        let heading = tree
+            .iter()
+            .flat_map(|element| element.descendants())
+            .find(|element| element.id == paragraph.id)
+            .unwrap();
+        assert_eq!(heading.heading_level(), Some(1));
+        assert_eq!(heading.alt_text.as_deref(), Some("Title of the report"));
+        assert_eq!(heading.actual_text.as_deref(), Some("Annual Report"));
+
+        assert!(doc.set_structure_role("missing", "P").is_err());
+        assert!(doc.set_structure_role(&paragraph.id, "").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn pdf_einvoice() -> Result<(), Box<dyn std::error::Error>> {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rsm:CrossIndustryInvoice xmlns:rsm="urn:un:unece:uncefact:data:standard:CrossIndustryInvoice:100">
+  <rsm:ExchangedDocument><ram:ID xmlns:ram="urn:un:unece:uncefact:data:standard:ReusableAggregateBusinessInformationEntity:100">INV-42</ram:ID></rsm:ExchangedDocument>
+</rsm:CrossIndustryInvoice>"#;
+
+        let doc = Document::new()?;
+        doc.page_add()?;
+        doc.page_add_text(1, "Invoice INV-42")?;
+        assert_eq!(doc.extract_einvoice()?, None);
+        assert!(doc
+            .embed_einvoice("<Invoice/>", FacturXProfile::En16931)
+            .is_err());
+
+        let report = doc.embed_einvoice(xml, FacturXProfile::En16931)?;
+        assert!(report.is_compliant());
+        assert!(doc.validate_pdfa(PdfAFormat::A3b)?.is_compliant());
+
+        let attachments = doc.attachments()?;
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].name, "factur-x.xml");
+        assert_eq!(
+            attachments[0].relationship,
+            Some(AfRelationship::Alternative)
+        );
+        assert_eq!(
+            doc.xmp_property(FACTURX_NAMESPACE, "ConformanceLevel")?,
+            Some("EN 16931".to_string())
+        );
+
+        // Embedding again replaces the invoice
+        assert!(doc
+            .embed_einvoice(xml, FacturXProfile::Basic)?
+            .is_compliant());
+        assert_eq!(doc.attachments()?.len(), 1);
+        assert_eq!(
+            doc.xmp()?
+                .matches("<pdfaSchema:prefix>fx</pdfaSchema:prefix>")
+                .count(),
+            1
+        );
+
+        // Read the invoice back from the saved PDF-document
+        let incoming = Document::open_bytes(&doc.bytes()?)?;
+        let invoice = incoming.extract_einvoice()?.unwrap();
+        assert_eq!(invoice.file_name, "factur-x.xml");
+        assert_eq!(invoice.xml, xml);
+        assert_eq!(invoice.profile, Some(FacturXProfile::Basic));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "core-preview")]
+    fn einvoice_syntax() {
+        let cii = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Exported by the billing system -->
+<rsm:CrossIndustryInvoice xmlns:rsm="urn:un:unece:uncefact:data:standard:CrossIndustryInvoice:100"
+    xmlns:ram='urn:un:unece:uncefact:data:standard:ReusableAggregateBusinessInformationEntity:100'>
+</rsm:CrossIndustryInvoice>"#;
+        assert!(einvoice::check_cii_invoice(cii).is_ok());
+        assert!(einvoice::check_cii_invoice(
+            r#"<CrossIndustryInvoice xmlns="urn:un:unece:uncefact:data:standard:CrossIndustryInvoice:100"/>"#
+        )
+        .is_ok());
+
+        // A UBL invoice is reported as such
+        let ubl = r#"<?xml version="1.0"?>
+<Invoice xmlns="urn:oasis:names:specification:ubl:schema:xsd:Invoice-2">
+  <cbc:Note>Not a CrossIndustryInvoice</cbc:Note>
+</Invoice>"#;
+        let error = einvoice::check_cii_invoice(ubl).unwrap_err().to_string();
+        assert!(error.contains("UBL"), "{}", error);
+
+        // The name alone, in a comment or without the namespace, is not enough
+        assert!(einvoice::check_cii_invoice(
+            "<!-- CrossIndustryInvoice --><Invoice xmlns=\"urn:example\"/>"
+        )
+        .is_err());
+        assert!(einvoice::check_cii_invoice("<rsm:CrossIndustryInvoice/>").is_err());
+        assert!(einvoice::check_cii_invoice("<Invoice/>").is_err());
+        assert!(einvoice::check_cii_invoice("").is_err());
+    }
+
+    #[test]
+    fn facturx_profile() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            "basic wl".parse::<FacturXProfile>()?,
+            FacturXProfile::BasicWl
+        );
+        assert_eq!(FacturXProfile::Minimum.relationship(), AfRelationship::Data);
+
+        Ok(())
+    }
 }
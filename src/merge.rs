@@ -0,0 +1,150 @@
+#[cfg(feature = "core-preview")]
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+/// FieldRenaming defines how form field names are changed when merging PDF-documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FieldRenaming {
+    /// Keep the original names; fields with the same name share one value.
+    #[default]
+    Keep,
+    /// Prepend the label of the source PDF-document (e.g. `doc1_name`).
+    Prefix,
+    /// Append the label of the source PDF-document (e.g. `name_doc1`).
+    Suffix,
+}
+
+/// MergeOptions defines how `Document::merge_documents_with_options` and `Document::append_with_options`
+/// combine the form fields of the source PDF-documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeOptions {
+    pub field_renaming: FieldRenaming,
+
+    /// The string placed between the label and the original field name.
+    pub separator: String,
+
+    /// The labels of the source PDF-documents, in merge order.
+    /// Empty to use `doc1`, `doc2`, ... .
+    pub labels: Vec<String>,
+
+    /// Whether to rename only the fields whose names occur in more than one source PDF-document.
+    pub only_conflicting: bool,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        MergeOptions {
+            field_renaming: FieldRenaming::Keep,
+            separator: "_".to_string(),
+            labels: Vec::new(),
+            only_conflicting: false,
+        }
+    }
+}
+
+impl MergeOptions {
+    /// Prefix the field names with the label of the source PDF-document.
+    pub fn prefix() -> Self {
+        MergeOptions {
+            field_renaming: FieldRenaming::Prefix,
+            ..Default::default()
+        }
+    }
+
+    /// Suffix the field names with the label of the source PDF-document.
+    pub fn suffix() -> Self {
+        MergeOptions {
+            field_renaming: FieldRenaming::Suffix,
+            ..Default::default()
+        }
+    }
+
+    /// Return the options with the specified separator.
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Return the options with the specified labels of the source PDF-documents.
+    pub fn with_labels(mut self, labels: &[&str]) -> Self {
+        self.labels = labels.iter().map(|label| label.to_string()).collect();
+        self
+    }
+
+    /// Return the options renaming only conflicting field names.
+    pub fn only_conflicting(mut self) -> Self {
+        self.only_conflicting = true;
+        self
+    }
+
+    // Returns the new name of the field of the source PDF-document at `index`,
+    // or `None` if the field keeps its name.
+    #[cfg(feature = "core-preview")]
+    pub(crate) fn rename(&self, index: usize, name: &str) -> Option<String> {
+        let label = self
+            .labels
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| format!("doc{}", index + 1));
+        match self.field_renaming {
+            FieldRenaming::Keep => None,
+            FieldRenaming::Prefix => Some(format!("{}{}{}", label, self.separator, name)),
+            FieldRenaming::Suffix => Some(format!("{}{}{}", name, self.separator, label)),
+        }
+    }
+}
+
+// Returns the renamed fields of each source PDF-document, given the field names of the source PDF-documents.
+// A new name already used by another field gets a numeric suffix (e.g. `doc1_name_2`), so that renaming
+// never collides with an existing field.
+#[cfg(feature = "core-preview")]
+pub(crate) fn field_renames(
+    field_names: &[Vec<String>],
+    options: &MergeOptions,
+) -> Vec<Vec<FieldRename>> {
+    let mut occurrences: HashMap<&str, usize> = HashMap::new();
+    for name in field_names.iter().flatten() {
+        *occurrences.entry(name.as_str()).or_default() += 1;
+    }
+
+    let mut taken: HashSet<String> = field_names.iter().flatten().cloned().collect();
+    let mut renames = Vec::with_capacity(field_names.len());
+    for (document, names) in field_names.iter().enumerate() {
+        let mut doc_renames = Vec::new();
+        for name in names {
+            if options.only_conflicting && occurrences[name.as_str()] <= 1 {
+                continue;
+            }
+            let Some(new_name) = options.rename(document, name) else {
+                continue;
+            };
+            let new_name = (1..)
+                .map(|n| match n {
+                    1 => new_name.clone(),
+                    n => format!("{}{}{}", new_name, options.separator, n),
+                })
+                .find(|candidate| !taken.contains(candidate))
+                .unwrap();
+            taken.insert(new_name.clone());
+            doc_renames.push(FieldRename {
+                document,
+                old_name: name.clone(),
+                new_name,
+            });
+        }
+        renames.push(doc_renames);
+    }
+    renames
+}
+
+/// FieldRename describes a form field renamed while merging PDF-documents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldRename {
+    /// The index of the source PDF-document (0-based).
+    pub document: usize,
+
+    pub old_name: String,
+
+    pub new_name: String,
+}
@@ -0,0 +1,258 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::PdfError;
+
+/// PdfDate is a date in the PDF date format `D:YYYYMMDDHHmmSSOHH'mm'`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PdfDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    /// Offset from UTC in minutes (e.g. `120` for `+02'00'`).
+    pub utc_offset_minutes: i16,
+}
+
+impl PdfDate {
+    /// Create a UTC date.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if a field is out of range, including a day that does not exist in the month.
+    pub fn new(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, PdfError> {
+        if !(1..=12).contains(&month)
+            || !(1..=days_in_month(year, month)).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return Err(PdfError::CoreExceptionError(format!(
+                "invalid PDF date: {:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                year, month, day, hour, minute, second
+            )));
+        }
+        Ok(PdfDate {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            utc_offset_minutes: 0,
+        })
+    }
+
+    /// Return the same local date and time with the specified offset from UTC in minutes.
+    ///
+    /// # Errors
+    /// Returns `PdfError` if the offset exceeds 23 hours and 59 minutes.
+    pub fn with_utc_offset(mut self, utc_offset_minutes: i16) -> Result<Self, PdfError> {
+        if utc_offset_minutes.unsigned_abs() > MAX_UTC_OFFSET_MINUTES {
+            return Err(PdfError::CoreExceptionError(format!(
+                "invalid UTC offset: {} minutes",
+                utc_offset_minutes
+            )));
+        }
+        self.utc_offset_minutes = utc_offset_minutes;
+        Ok(self)
+    }
+}
+
+// The largest offset from UTC that the PDF date format can express (23'59').
+const MAX_UTC_OFFSET_MINUTES: u16 = 23 * 60 + 59;
+
+impl FromStr for PdfDate {
+    type Err = PdfError;
+
+    /// Parse a date in the PDF date format.
+    ///
+    /// All fields after the year are optional, as allowed by the PDF specification.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || PdfError::CoreExceptionError(format!("invalid PDF date: {:?}", s));
+        let value = s.trim();
+        let value = value.strip_prefix("D:").unwrap_or(value);
+
+        let digits_len = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        if digits_len < 4 || digits_len % 2 != 0 || digits_len > 14 {
+            return Err(invalid());
+        }
+        let (digits, zone) = value.split_at(digits_len);
+        let field = |start: usize, len: usize, default: u32| -> u32 {
+            digits
+                .get(start..start + len)
+                .and_then(|f| f.parse().ok())
+                .unwrap_or(default)
+        };
+
+        let date = PdfDate::new(
+            field(0, 4, 0) as i32,
+            field(4, 2, 1) as u8,
+            field(6, 2, 1) as u8,
+            field(8, 2, 0) as u8,
+            field(10, 2, 0) as u8,
+            field(12, 2, 0) as u8,
+        )
+        .map_err(|_| invalid())?;
+
+        let utc_offset_minutes = match zone.chars().next() {
+            None | Some('Z') => 0,
+            Some(sign @ ('+' | '-')) => {
+                let offset: Vec<&str> = zone[1..]
+                    .split('\'')
+                    .filter(|part| !part.is_empty())
+                    .collect();
+                let hours: u8 = offset
+                    .first()
+                    .and_then(|h| h.parse().ok())
+                    .ok_or_else(invalid)?;
+                let minutes: u8 = match offset.get(1) {
+                    Some(m) => m.parse().map_err(|_| invalid())?,
+                    None => 0,
+                };
+                if hours > 23 || minutes > 59 {
+                    return Err(invalid());
+                }
+                let total = hours as i16 * 60 + minutes as i16;
+                if sign == '-' {
+                    -total
+                } else {
+                    total
+                }
+            }
+            Some(_) => return Err(invalid()),
+        };
+
+        date.with_utc_offset(utc_offset_minutes)
+            .map_err(|_| invalid())
+    }
+}
+
+// Returns the number of days in the month of the year.
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for PdfDate {
+    /// Formats the date in the PDF date format.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "D:{:04}{:02}{:02}{:02}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.utc_offset_minutes == 0 {
+            write!(f, "Z")
+        } else {
+            let sign = if self.utc_offset_minutes < 0 {
+                '-'
+            } else {
+                '+'
+            };
+            let offset = self.utc_offset_minutes.unsigned_abs();
+            write!(f, "{}{:02}'{:02}'", sign, offset / 60, offset % 60)
+        }
+    }
+}
+
+impl Serialize for PdfDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PdfDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// Deserializes an optional date, reading a date that is not in the PDF date format as `None`
+// so that one malformed date written by another producer does not make the whole object unreadable.
+pub(crate) fn deserialize_lenient_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<PdfDate>, D::Error> {
+    let value = Option::<String>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| value.parse().ok()))
+}
+
+/// Metadata contains the fields of the PDF-document information dictionary.
+///
+/// Fields set to `None` are absent from the PDF-document; dates that are not in the PDF date format are read as `None`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(rename = "title", default)]
+    pub title: Option<String>,
+
+    #[serde(rename = "author", default)]
+    pub author: Option<String>,
+
+    #[serde(rename = "subject", default)]
+    pub subject: Option<String>,
+
+    #[serde(rename = "keywords", default)]
+    pub keywords: Option<String>,
+
+    #[serde(rename = "creator", default)]
+    pub creator: Option<String>,
+
+    #[serde(rename = "producer", default)]
+    pub producer: Option<String>,
+
+    #[serde(
+        rename = "creationdate",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub creation_date: Option<PdfDate>,
+
+    #[serde(
+        rename = "moddate",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub mod_date: Option<PdfDate>,
+}
+
+impl fmt::Display for Metadata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = |value: &Option<String>| value.clone().unwrap_or_default();
+        let date = |value: &Option<PdfDate>| value.map(|d| d.to_string()).unwrap_or_default();
+        write!(
+            f,
+            "Title:        {}\n\
+             Author:       {}\n\
+             Subject:      {}\n\
+             Keywords:     {}\n\
+             Creator:      {}\n\
+             Producer:     {}\n\
+             CreationDate: {}\n\
+             ModDate:      {}",
+            text(&self.title),
+            text(&self.author),
+            text(&self.subject),
+            text(&self.keywords),
+            text(&self.creator),
+            text(&self.producer),
+            date(&self.creation_date),
+            date(&self.mod_date)
+        )
+    }
+}
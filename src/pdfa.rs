@@ -0,0 +1,84 @@
+use serde::Deserialize;
+
+/// Violation is a failed requirement of a PDF standard.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Violation {
+    /// The clause of the standard (e.g. `"6.2.11.4.1"`).
+    #[serde(rename = "clause")]
+    pub clause: String,
+
+    #[serde(rename = "description")]
+    pub description: String,
+
+    /// The offending object (e.g. `"12 0 obj"`), `None` for document-level requirements.
+    #[serde(rename = "object", default)]
+    pub object: Option<String>,
+
+    /// The page number (1-based), `None` if the object is not on a page.
+    #[serde(rename = "page", default)]
+    pub page: Option<i32>,
+}
+
+/// ValidationReport lists the violations found by `Document::validate_pdfa`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ValidationReport {
+    #[serde(rename = "violations", default)]
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    /// Return true if no requirement is violated.
+    pub fn is_compliant(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Return the violations on the specified page (1-based).
+    pub fn page_violations(&self, page: i32) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.page == Some(page))
+    }
+}
+
+/// PdfAFix is a change made by `Document::convert_to_pdfa` to satisfy a requirement.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PdfAFix {
+    /// The clause of the standard (e.g. `"6.4"`).
+    #[serde(rename = "clause")]
+    pub clause: String,
+
+    /// What was changed (e.g. "Removed transparency group").
+    #[serde(rename = "description")]
+    pub description: String,
+
+    /// Whether content or appearance was lost (e.g. removed JavaScript, flattened transparency).
+    #[serde(rename = "lossy", default)]
+    pub lossy: bool,
+
+    /// The page number (1-based), `None` if the change is not on a page.
+    #[serde(rename = "page", default)]
+    pub page: Option<i32>,
+}
+
+/// PdfAConversionReport lists the result of `Document::convert_to_pdfa`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PdfAConversionReport {
+    #[serde(rename = "fixes", default)]
+    pub fixes: Vec<PdfAFix>,
+
+    /// The violations that could not be fixed.
+    #[serde(rename = "violations", default)]
+    pub violations: Vec<Violation>,
+}
+
+impl PdfAConversionReport {
+    /// Return true if the converted PDF-document complies with the requested format.
+    pub fn is_compliant(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Return the fixes that lost content or changed the appearance.
+    pub fn lossy_fixes(&self) -> impl Iterator<Item = &PdfAFix> {
+        self.fixes.iter().filter(|fix| fix.lossy)
+    }
+}
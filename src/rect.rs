@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Rect is a rectangle in page coordinates (points, origin at the lower-left corner of the page).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Rect {
+    /// X-coordinate of the lower-left corner.
+    #[serde(rename = "llx")]
+    pub llx: f64,
+
+    /// Y-coordinate of the lower-left corner.
+    #[serde(rename = "lly")]
+    pub lly: f64,
+
+    /// X-coordinate of the upper-right corner.
+    #[serde(rename = "urx")]
+    pub urx: f64,
+
+    /// Y-coordinate of the upper-right corner.
+    #[serde(rename = "ury")]
+    pub ury: f64,
+}
+
+impl Rect {
+    /// Create a rectangle from its lower-left and upper-right corners.
+    pub fn new(llx: f64, lly: f64, urx: f64, ury: f64) -> Self {
+        Rect { llx, lly, urx, ury }
+    }
+
+    /// Return the width of the rectangle.
+    pub fn width(&self) -> f64 {
+        self.urx - self.llx
+    }
+
+    /// Return the height of the rectangle.
+    pub fn height(&self) -> f64 {
+        self.ury - self.lly
+    }
+}
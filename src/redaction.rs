@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rect::Rect;
+
+/// RedactionPattern defines the text to search for when marking areas for redaction.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "value")]
+pub enum RedactionPattern {
+    /// U.S. Social Security Numbers (e.g. `123-45-6789`).
+    #[serde(rename = "ssn")]
+    Ssn,
+    /// E-mail addresses.
+    #[serde(rename = "email")]
+    Email,
+    /// Exact text phrase.
+    #[serde(rename = "text")]
+    Text(String),
+    /// Custom regular expression.
+    #[serde(rename = "regex")]
+    Regex(String),
+}
+
+/// RedactionMark marks an area of the PDF-document for redaction.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type")]
+pub enum RedactionMark {
+    /// A rectangle on the specified page (1-based).
+    #[serde(rename = "area")]
+    Area {
+        #[serde(rename = "page")]
+        page: i32,
+        #[serde(rename = "rect")]
+        rect: Rect,
+    },
+    /// All matches of the search pattern on the specified page, or on all pages if `page` is `None`.
+    #[serde(rename = "pattern")]
+    Pattern {
+        #[serde(rename = "pattern")]
+        pattern: RedactionPattern,
+        #[serde(rename = "page")]
+        page: Option<i32>,
+    },
+}
+
+impl RedactionMark {
+    /// Mark a rectangle on the specified page (1-based).
+    pub fn area(page: i32, rect: Rect) -> Self {
+        RedactionMark::Area { page, rect }
+    }
+
+    /// Mark all matches of the search pattern in the PDF-document.
+    pub fn pattern(pattern: RedactionPattern) -> Self {
+        RedactionMark::Pattern {
+            pattern,
+            page: None,
+        }
+    }
+
+    /// Mark all matches of the search pattern on the specified page (1-based).
+    pub fn page_pattern(page: i32, pattern: RedactionPattern) -> Self {
+        RedactionMark::Pattern {
+            pattern,
+            page: Some(page),
+        }
+    }
+}
+
+/// RedactionAppearance defines how redacted areas are drawn after the content is removed.
+#[derive(Debug, Clone, Serialize)]
+pub struct RedactionAppearance {
+    /// The fill color of the box (hexadecimal format "#RRGGBB").
+    #[serde(rename = "fillcolor")]
+    pub fill_color: String,
+
+    /// The optional label drawn over the box (e.g. "REDACTED").
+    #[serde(rename = "overlaytext")]
+    pub overlay_text: Option<String>,
+
+    /// The font name of the label.
+    #[serde(rename = "fontname")]
+    pub font_name: String,
+
+    /// The font size of the label.
+    #[serde(rename = "fontsize")]
+    pub font_size: f64,
+
+    /// The text color of the label (hexadecimal format "#RRGGBB").
+    #[serde(rename = "textcolor")]
+    pub text_color: String,
+}
+
+impl Default for RedactionAppearance {
+    fn default() -> Self {
+        RedactionAppearance {
+            fill_color: "#000000".to_string(),
+            overlay_text: None,
+            font_name: "Helvetica".to_string(),
+            font_size: 10.0,
+            text_color: "#FFFFFF".to_string(),
+        }
+    }
+}
+
+/// RedactedContent is the kind of content removed from the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum RedactedContent {
+    /// Text.
+    #[serde(rename = "text")]
+    Text,
+    /// Image pixels.
+    #[serde(rename = "image")]
+    Image,
+    /// Vector graphics.
+    #[serde(rename = "vector")]
+    Vector,
+}
+
+/// RedactedItem describes a piece of content removed from the page.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RedactedItem {
+    /// The page number (1-based).
+    #[serde(rename = "page")]
+    pub page: i32,
+
+    /// The area of the removed content.
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+
+    /// The kind of the removed content.
+    #[serde(rename = "content")]
+    pub content: RedactedContent,
+
+    /// The removed text, if the content is text.
+    #[serde(rename = "text", default)]
+    pub text: Option<String>,
+}
+
+/// RedactionReport lists the content removed by `Document::redact`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RedactionReport {
+    #[serde(rename = "items", default)]
+    pub items: Vec<RedactedItem>,
+}
+
+impl RedactionReport {
+    /// Return true if nothing was removed.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Return the removed items on the specified page (1-based).
+    pub fn page_items(&self, page: i32) -> impl Iterator<Item = &RedactedItem> {
+        self.items.iter().filter(move |item| item.page == page)
+    }
+}
@@ -0,0 +1,186 @@
+use serde::Serialize;
+
+#[cfg(feature = "image")]
+use crate::errors::PdfError;
+use crate::rect::Rect;
+
+/// RenderResolution defines the size of a rendered page.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum RenderResolution {
+    /// Render at the specified resolution in dots per inch.
+    #[serde(rename = "dpi")]
+    Dpi {
+        #[serde(rename = "dpi")]
+        dpi: f64,
+    },
+    /// Scale the page, keeping its aspect ratio, to fit within the specified size in pixels.
+    #[serde(rename = "size")]
+    Size {
+        #[serde(rename = "width")]
+        width: u32,
+        #[serde(rename = "height")]
+        height: u32,
+    },
+}
+
+/// RenderOptions defines how `Document::render_page` rasterizes a page.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenderOptions {
+    #[serde(rename = "resolution")]
+    pub resolution: RenderResolution,
+
+    /// Whether to leave the page background transparent instead of white.
+    #[serde(rename = "transparentbackground")]
+    pub transparent_background: bool,
+
+    #[serde(rename = "antialiasing")]
+    pub anti_aliasing: bool,
+
+    #[serde(rename = "renderannotations")]
+    pub render_annotations: bool,
+
+    /// The region of the page to render, `None` for the whole page.
+    #[serde(rename = "clip")]
+    pub clip: Option<Rect>,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            resolution: RenderResolution::Dpi { dpi: 150.0 },
+            transparent_background: false,
+            anti_aliasing: true,
+            render_annotations: true,
+            clip: None,
+        }
+    }
+}
+
+impl RenderOptions {
+    /// Render at the specified resolution in dots per inch.
+    pub fn dpi(dpi: f64) -> Self {
+        RenderOptions {
+            resolution: RenderResolution::Dpi { dpi },
+            ..Default::default()
+        }
+    }
+
+    /// Render to fit within the specified size in pixels.
+    pub fn size(width: u32, height: u32) -> Self {
+        RenderOptions {
+            resolution: RenderResolution::Size { width, height },
+            ..Default::default()
+        }
+    }
+
+    /// Return the options with a transparent background.
+    pub fn with_transparent_background(mut self) -> Self {
+        self.transparent_background = true;
+        self
+    }
+
+    /// Return the options with anti-aliasing enabled or disabled.
+    pub fn with_anti_aliasing(mut self, anti_aliasing: bool) -> Self {
+        self.anti_aliasing = anti_aliasing;
+        self
+    }
+
+    /// Return the options with annotation rendering enabled or disabled.
+    pub fn with_annotations(mut self, render_annotations: bool) -> Self {
+        self.render_annotations = render_annotations;
+        self
+    }
+
+    /// Return the options rendering only the specified region of the page.
+    pub fn with_clip(mut self, clip: Rect) -> Self {
+        self.clip = Some(clip);
+        self
+    }
+}
+
+/// RgbaImage is a raster image with 8-bit RGBA pixels (not premultiplied), row by row from the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    pub width: u32,
+
+    pub height: u32,
+
+    /// The number of bytes between the starts of two consecutive rows.
+    pub stride: usize,
+
+    pub data: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Return true if the stride holds a row of pixels and the data holds all rows.
+    pub fn is_valid(&self) -> bool {
+        let row_len = self.width as usize * 4;
+        let required = match self.height as usize {
+            0 => Some(0),
+            height => self
+                .stride
+                .checked_mul(height - 1)
+                .and_then(|len| len.checked_add(row_len)),
+        };
+        self.stride >= row_len && required.is_some_and(|required| self.data.len() >= required)
+    }
+
+    /// Return the pixel at the specified position as `[r, g, b, a]`.
+    ///
+    /// # Panics
+    /// Panics if the position is outside the image or the image is not valid.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        let offset = y as usize * self.stride + x as usize * 4;
+        [
+            self.data[offset],
+            self.data[offset + 1],
+            self.data[offset + 2],
+            self.data[offset + 3],
+        ]
+    }
+
+    /// Return the pixel rows without the stride padding, no rows if the image is not valid.
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        let row_len = self.width as usize * 4;
+        let height = if self.is_valid() {
+            self.height as usize
+        } else {
+            0
+        };
+        self.data
+            .chunks(self.stride.max(1))
+            .take(height)
+            .map(move |row| &row[..row_len])
+    }
+}
+
+#[cfg(feature = "image")]
+impl TryFrom<RgbaImage> for image::DynamicImage {
+    type Error = PdfError;
+
+    fn try_from(rgba: RgbaImage) -> Result<Self, PdfError> {
+        let invalid = PdfError::CoreExceptionError(format!(
+            "RgbaImage: {} bytes with stride {} do not hold {}x{} pixels",
+            rgba.data.len(),
+            rgba.stride,
+            rgba.width,
+            rgba.height
+        ));
+        if !rgba.is_valid() {
+            return Err(invalid);
+        }
+        let (width, height) = (rgba.width, rgba.height);
+        let data = if rgba.stride == width as usize * 4 {
+            let mut data = rgba.data;
+            data.truncate(rgba.stride * height as usize);
+            data
+        } else {
+            rgba.rows().flatten().copied().collect()
+        };
+        image::RgbaImage::from_raw(width, height, data)
+            .map(image::DynamicImage::ImageRgba8)
+            .ok_or(invalid)
+    }
+}
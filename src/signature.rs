@@ -0,0 +1,461 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[cfg(feature = "core-preview")]
+use crate::document::Document;
+use crate::errors::PdfError;
+use crate::metadata::{deserialize_lenient_date, PdfDate};
+use crate::rect::Rect;
+
+/// Certificate is an X.509 certificate embedded in a signature.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Certificate {
+    /// The distinguished name of the subject (e.g. `"CN=John Doe, O=Example"`).
+    #[serde(rename = "subject")]
+    pub subject: String,
+
+    /// The distinguished name of the issuer.
+    #[serde(rename = "issuer")]
+    pub issuer: String,
+
+    /// The serial number (hexadecimal).
+    #[serde(rename = "serialnumber")]
+    pub serial_number: String,
+
+    #[serde(
+        rename = "notbefore",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub not_before: Option<PdfDate>,
+
+    #[serde(
+        rename = "notafter",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub not_after: Option<PdfDate>,
+
+    /// The DER encoding of the certificate.
+    #[serde(rename = "der", default)]
+    pub der: Vec<u8>,
+}
+
+impl Certificate {
+    /// Return true if the certificate is self-signed (the subject is the issuer).
+    pub fn is_self_signed(&self) -> bool {
+        self.subject == self.issuer
+    }
+}
+
+/// SignatureInfo describes a signature field of the PDF-document.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SignatureInfo {
+    /// The fully qualified name of the signature field.
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// The certificate chain, starting with the signer certificate.
+    #[serde(rename = "certificates", default)]
+    pub certificates: Vec<Certificate>,
+
+    /// The signing time claimed by the signer, if present.
+    #[serde(
+        rename = "signingtime",
+        default,
+        deserialize_with = "deserialize_lenient_date"
+    )]
+    pub signing_time: Option<PdfDate>,
+
+    #[serde(rename = "reason", default)]
+    pub reason: Option<String>,
+
+    #[serde(rename = "location", default)]
+    pub location: Option<String>,
+
+    #[serde(rename = "contact", default)]
+    pub contact: Option<String>,
+
+    /// The signature encoding (e.g. `"adbe.pkcs7.detached"`, `"ETSI.CAdES.detached"`).
+    #[serde(rename = "subfilter", default)]
+    pub sub_filter: Option<String>,
+
+    /// The signed byte ranges as pairs of offset and length.
+    #[serde(rename = "byterange", default)]
+    pub byte_range: Vec<i64>,
+
+    /// Whether the byte ranges cover the whole file except the signature value,
+    /// false if the PDF-document was changed by an incremental update after signing.
+    #[serde(rename = "coverswholedocument", default)]
+    pub covers_whole_document: bool,
+}
+
+impl SignatureInfo {
+    /// Return the signer certificate, the first certificate of the chain.
+    pub fn signer(&self) -> Option<&Certificate> {
+        self.certificates.first()
+    }
+
+    /// Return the number of signed bytes.
+    pub fn signed_length(&self) -> i64 {
+        self.byte_range.iter().skip(1).step_by(2).sum()
+    }
+}
+
+/// TrustStore holds the trusted root certificates used by `Document::verify_signature`.
+///
+/// Verification is offline: only the certificates of the trust store and of the signature are used,
+/// revocation information is checked only if it is embedded in the PDF-document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TrustStore {
+    #[serde(rename = "certificates")]
+    certificates: Vec<Vec<u8>>,
+
+    #[serde(rename = "verificationtime", skip_serializing_if = "Option::is_none")]
+    verification_time: Option<PdfDate>,
+}
+
+impl TrustStore {
+    /// Create an empty trust store.
+    pub fn new() -> Self {
+        TrustStore::default()
+    }
+
+    /// Add a trusted certificate in DER or PEM format; a PEM file may contain several certificates.
+    pub fn with_certificate(mut self, data: &[u8]) -> Self {
+        self.add_certificate(data);
+        self
+    }
+
+    /// Add a trusted certificate in DER or PEM format; a PEM file may contain several certificates.
+    pub fn add_certificate(&mut self, data: &[u8]) {
+        self.certificates.push(data.to_vec());
+    }
+
+    /// Validate the certificates at the specified time instead of the signing time.
+    pub fn with_verification_time(mut self, time: PdfDate) -> Self {
+        self.verification_time = Some(time);
+        self
+    }
+
+    /// Return the number of added certificates or PEM files.
+    pub fn len(&self) -> usize {
+        self.certificates.len()
+    }
+
+    /// Return true if no certificate is added.
+    pub fn is_empty(&self) -> bool {
+        self.certificates.is_empty()
+    }
+}
+
+/// VerificationResult is the result of `Document::verify_signature`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct VerificationResult {
+    /// The name of the signature field.
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// Whether the digest of the signed byte ranges matches the signature.
+    #[serde(rename = "integrity")]
+    pub integrity: bool,
+
+    /// Whether the certificate chain leads to a certificate of the trust store.
+    #[serde(rename = "trusted")]
+    pub trusted: bool,
+
+    /// Whether the certificates of the chain were valid at the verification time.
+    #[serde(rename = "certificatesvalid")]
+    pub certificates_valid: bool,
+
+    /// Whether the signature covers the whole PDF-document.
+    #[serde(rename = "coverswholedocument", default)]
+    pub covers_whole_document: bool,
+
+    /// The reasons of failed checks.
+    #[serde(rename = "errors", default)]
+    pub errors: Vec<String>,
+}
+
+impl VerificationResult {
+    /// Return true if the signature is intact and issued by a trusted, valid certificate chain.
+    ///
+    /// Changes made after signing are allowed; check `covers_whole_document` to reject them.
+    pub fn is_valid(&self) -> bool {
+        self.integrity && self.trusted && self.certificates_valid
+    }
+}
+
+/// DigestAlgorithm is the message digest used to sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum DigestAlgorithm {
+    #[default]
+    #[serde(rename = "sha256")]
+    Sha256,
+    #[serde(rename = "sha384")]
+    Sha384,
+    #[serde(rename = "sha512")]
+    Sha512,
+}
+
+/// SignatureFormat is the encoding of the signature value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum SignatureFormat {
+    /// PKCS#7 signature of the digest (`adbe.pkcs7.sha1`), as `Document::sign_pkcs7` creates.
+    ///
+    /// The signed byte ranges are digested with SHA-1 as the format requires,
+    /// the digest algorithm applies to the PKCS#7 signature of that digest.
+    #[serde(rename = "pkcs7")]
+    Pkcs7,
+    /// PKCS#7 detached signature (`adbe.pkcs7.detached`), as `Document::sign_pkcs7_detached` creates.
+    #[default]
+    #[serde(rename = "pkcs7detached")]
+    Pkcs7Detached,
+    /// PAdES baseline signature (`ETSI.CAdES.detached`) with the signing certificate attribute.
+    #[serde(rename = "padesbaseline")]
+    PadesBaseline,
+}
+
+impl SignatureFormat {
+    /// Return the value of the `SubFilter` entry of the signature dictionary.
+    pub fn sub_filter(&self) -> &'static str {
+        match self {
+            SignatureFormat::Pkcs7 => "adbe.pkcs7.sha1",
+            SignatureFormat::Pkcs7Detached => "adbe.pkcs7.detached",
+            SignatureFormat::PadesBaseline => "ETSI.CAdES.detached",
+        }
+    }
+}
+
+/// SigningKey is a certificate chain and private key in PEM or DER format.
+#[derive(Clone, PartialEq, Serialize)]
+pub struct SigningKey {
+    /// The signer certificate first, then the intermediate certificates.
+    #[serde(rename = "certificates")]
+    certificates: Vec<Vec<u8>>,
+
+    #[serde(rename = "privatekey")]
+    private_key: Vec<u8>,
+
+    /// The password of an encrypted private key.
+    #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+}
+
+impl SigningKey {
+    /// Create a signing key from a certificate and a private key.
+    ///
+    /// The certificate can be a PEM file with the whole chain, signer certificate first,
+    /// or a single DER certificate; the private key can be PKCS#8, PKCS#1 or SEC1 in PEM or DER.
+    pub fn new(certificate: &[u8], private_key: &[u8]) -> Self {
+        SigningKey {
+            certificates: vec![certificate.to_vec()],
+            private_key: private_key.to_vec(),
+            password: None,
+        }
+    }
+
+    /// Read a signing key from a certificate file and a private key file.
+    ///
+    /// # Errors
+    /// Returns `PdfError::IoError` if a file cannot be read.
+    pub fn from_files(certificate_path: &str, private_key_path: &str) -> Result<Self, PdfError> {
+        let certificate = std::fs::read(certificate_path).map_err(PdfError::IoError)?;
+        let private_key = std::fs::read(private_key_path).map_err(PdfError::IoError)?;
+        Ok(SigningKey::new(&certificate, &private_key))
+    }
+
+    /// Return the signing key with an intermediate certificate in PEM or DER format appended to the chain.
+    pub fn with_certificate(mut self, certificate: &[u8]) -> Self {
+        self.certificates.push(certificate.to_vec());
+        self
+    }
+
+    /// Return the signing key with the password of the encrypted private key.
+    pub fn with_password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("certificates", &self.certificates.len())
+            .field("private_key", &"<redacted>")
+            .finish()
+    }
+}
+
+/// SignatureOptions defines the signature created by `Document::sign_with_certificate`.
+///
+/// The default options create an invisible PKCS#7 detached signature with SHA-256 on page 1.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SignatureOptions {
+    /// The page number (1-based) of the signature field.
+    #[serde(rename = "page")]
+    pub page: i32,
+
+    /// The rectangle of a visible signature, `None` for an invisible signature.
+    #[serde(rename = "rect", skip_serializing_if = "Option::is_none")]
+    pub rect: Option<Rect>,
+
+    /// The raw bytes of the graphic appearance of a visible signature.
+    #[serde(rename = "appearance", skip_serializing_if = "Vec::is_empty")]
+    pub appearance: Vec<u8>,
+
+    /// The name of the signature field, generated if `None`.
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub field_name: Option<String>,
+
+    #[serde(rename = "reason", skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    #[serde(rename = "contact", skip_serializing_if = "Option::is_none")]
+    pub contact: Option<String>,
+
+    #[serde(rename = "location", skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    #[serde(rename = "digest")]
+    pub digest: DigestAlgorithm,
+
+    #[serde(rename = "format")]
+    pub format: SignatureFormat,
+}
+
+impl Default for SignatureOptions {
+    fn default() -> Self {
+        SignatureOptions {
+            page: 1,
+            rect: None,
+            appearance: Vec::new(),
+            field_name: None,
+            reason: None,
+            contact: None,
+            location: None,
+            digest: DigestAlgorithm::default(),
+            format: SignatureFormat::default(),
+        }
+    }
+}
+
+impl SignatureOptions {
+    /// Create options for an invisible signature.
+    pub fn new() -> Self {
+        SignatureOptions::default()
+    }
+
+    /// Create options for a PAdES baseline signature.
+    pub fn pades() -> Self {
+        SignatureOptions {
+            format: SignatureFormat::PadesBaseline,
+            ..Default::default()
+        }
+    }
+
+    /// Return the options with a visible signature at the specified rectangle of a page (1-based).
+    pub fn visible(mut self, page: i32, rect: Rect) -> Self {
+        self.page = page;
+        self.rect = Some(rect);
+        self
+    }
+
+    /// Return the options with the graphic appearance of a visible signature.
+    pub fn with_appearance(mut self, appearance: &[u8]) -> Self {
+        self.appearance = appearance.to_vec();
+        self
+    }
+
+    /// Return the options with the specified name of the signature field.
+    pub fn with_field_name(mut self, name: &str) -> Self {
+        self.field_name = Some(name.to_string());
+        self
+    }
+
+    /// Return the options with the specified reason of the signature.
+    pub fn with_reason(mut self, reason: &str) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    /// Return the options with the specified contact of the signer.
+    pub fn with_contact(mut self, contact: &str) -> Self {
+        self.contact = Some(contact.to_string());
+        self
+    }
+
+    /// Return the options with the specified location of the signing.
+    pub fn with_location(mut self, location: &str) -> Self {
+        self.location = Some(location.to_string());
+        self
+    }
+
+    /// Return the options with the specified digest algorithm.
+    pub fn with_digest(mut self, digest: DigestAlgorithm) -> Self {
+        self.digest = digest;
+        self
+    }
+
+    /// Return the options with the specified signature format.
+    pub fn with_format(mut self, format: SignatureFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Signer creates the CMS signature for `Document::sign_external`,
+/// e.g. with a hardware security module or a remote signing service.
+pub trait Signer {
+    /// Return the maximum size in bytes of the CMS returned by `sign`, reserved in the PDF-document.
+    ///
+    /// The default of 16 KiB fits a chain of a few RSA certificates; increase it for
+    /// longer chains or embedded timestamps and revocation data.
+    fn signature_size(&self) -> usize {
+        16384
+    }
+
+    /// Sign the digest of the signed byte ranges of the PDF-document.
+    ///
+    /// Return a DER-encoded CMS SignedData without encapsulated content, whose message digest
+    /// attribute is `digest`. For `SignatureFormat::PadesBaseline` it must contain the
+    /// signing certificate v2 attribute and no signing time attribute.
+    ///
+    /// # Errors
+    /// An error aborts the signing and is returned by `Document::sign_external`.
+    fn sign(
+        &self,
+        digest: &[u8],
+        algorithm: DigestAlgorithm,
+        format: SignatureFormat,
+    ) -> Result<Vec<u8>, PdfError>;
+}
+
+/// SoftwareSigner is a `Signer` that holds the private key in memory.
+///
+/// It is intended for tests and for keys that may leave a key store; use
+/// `Document::sign_with_certificate` to sign with such keys directly.
+#[derive(Debug, Clone)]
+#[cfg(feature = "core-preview")]
+pub struct SoftwareSigner {
+    key: SigningKey,
+}
+
+#[cfg(feature = "core-preview")]
+impl SoftwareSigner {
+    pub fn new(key: SigningKey) -> Self {
+        SoftwareSigner { key }
+    }
+}
+
+#[cfg(feature = "core-preview")]
+impl Signer for SoftwareSigner {
+    fn sign(
+        &self,
+        digest: &[u8],
+        algorithm: DigestAlgorithm,
+        format: SignatureFormat,
+    ) -> Result<Vec<u8>, PdfError> {
+        Document::create_cms(&self.key, digest, algorithm, format)
+    }
+}
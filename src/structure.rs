@@ -0,0 +1,66 @@
+use serde::Deserialize;
+
+/// StructureElement is a node of the logical structure tree of a tagged PDF-document.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StructureElement {
+    /// The identifier of the element, used by `Document::set_structure_role` and related methods.
+    #[serde(rename = "id")]
+    pub id: String,
+
+    /// The standard structure type after role mapping (e.g. `"H1"`, `"P"`, `"Table"`, `"TD"`, `"Figure"`, `"LI"`).
+    #[serde(rename = "role")]
+    pub role: String,
+
+    #[serde(rename = "title", default)]
+    pub title: Option<String>,
+
+    /// The alternate description (e.g. of a figure).
+    #[serde(rename = "alttext", default)]
+    pub alt_text: Option<String>,
+
+    /// The replacement text of the content (e.g. of a ligature or an image of text).
+    #[serde(rename = "actualtext", default)]
+    pub actual_text: Option<String>,
+
+    #[serde(rename = "language", default)]
+    pub language: Option<String>,
+
+    /// The text of the content marked directly by the element, excluding child elements.
+    #[serde(rename = "text", default)]
+    pub text: Option<String>,
+
+    /// The page numbers (1-based) of the content of the element and its children.
+    #[serde(rename = "pages", default)]
+    pub pages: Vec<i32>,
+
+    #[serde(rename = "children", default)]
+    pub children: Vec<StructureElement>,
+}
+
+impl StructureElement {
+    /// Return the heading level (1-6) for `H1`-`H6` elements, `None` otherwise.
+    pub fn heading_level(&self) -> Option<u8> {
+        match self.role.strip_prefix('H')?.parse::<u8>() {
+            Ok(level) if (1..=6).contains(&level) => Some(level),
+            _ => None,
+        }
+    }
+
+    /// Return the element and all its descendants in depth-first (reading) order.
+    pub fn descendants(&self) -> Vec<&StructureElement> {
+        let mut elements = vec![self];
+        for child in &self.children {
+            elements.extend(child.descendants());
+        }
+        elements
+    }
+
+    /// Return the text of the element including its children, in reading order.
+    pub fn full_text(&self) -> String {
+        self.descendants()
+            .iter()
+            .filter_map(|element| element.text.as_deref())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
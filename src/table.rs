@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use crate::errors::PdfError;
+use crate::rect::Rect;
+
+/// Cell is a cell of a table extracted from the PDF-document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cell {
+    #[serde(rename = "text")]
+    pub text: String,
+
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+
+    /// The number of rows the cell spans.
+    #[serde(rename = "rowspan", default = "default_span")]
+    pub row_span: usize,
+
+    /// The number of columns the cell spans.
+    #[serde(rename = "colspan", default = "default_span")]
+    pub col_span: usize,
+}
+
+fn default_span() -> usize {
+    1
+}
+
+/// Table is a table detected on a page of the PDF-document.
+///
+/// Each row lists only the cells that start in it; cells spanning several rows
+/// or columns are listed once, in the row and at the position of their top-left corner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Table {
+    /// The page number (1-based).
+    #[serde(rename = "page")]
+    pub page: i32,
+
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+
+    #[serde(rename = "rows")]
+    pub rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    /// Return the table as a grid of cell texts with spans resolved.
+    ///
+    /// The text of a spanning cell is placed in its top-left position, the other positions it covers are empty.
+    /// All rows have the same number of columns.
+    pub fn grid(&self) -> Vec<Vec<String>> {
+        let mut grid: Vec<Vec<Option<String>>> = Vec::new();
+        for (r, row) in self.rows.iter().enumerate() {
+            let mut c = 0;
+            for cell in row {
+                // Skip positions covered by cells spanning from the rows above
+                while grid
+                    .get(r)
+                    .and_then(|g| g.get(c))
+                    .is_some_and(Option::is_some)
+                {
+                    c += 1;
+                }
+                for dr in 0..cell.row_span.max(1) {
+                    for dc in 0..cell.col_span.max(1) {
+                        if grid.len() <= r + dr {
+                            grid.resize(r + dr + 1, Vec::new());
+                        }
+                        let grid_row = &mut grid[r + dr];
+                        if grid_row.len() <= c + dc {
+                            grid_row.resize(c + dc + 1, None);
+                        }
+                        grid_row[c + dc] = Some(if dr == 0 && dc == 0 {
+                            cell.text.clone()
+                        } else {
+                            String::new()
+                        });
+                    }
+                }
+                c += cell.col_span.max(1);
+            }
+        }
+
+        let columns = grid.iter().map(Vec::len).max().unwrap_or(0);
+        grid.into_iter()
+            .map(|row| {
+                let mut row: Vec<String> = row.into_iter().map(Option::unwrap_or_default).collect();
+                row.resize(columns, String::new());
+                row
+            })
+            .collect()
+    }
+
+    /// Return the table in CSV format (RFC 4180 quoting, one line per row).
+    pub fn to_csv(&self) -> String {
+        let quote = |text: &str| {
+            if text.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", text.replace('"', "\"\""))
+            } else {
+                text.to_string()
+            }
+        };
+        self.grid()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|text| quote(text))
+                    .collect::<Vec<_>>()
+                    .join(",")
+                    + "\n"
+            })
+            .collect()
+    }
+
+    /// Return the table in JSON format, including cell rectangles and spans.
+    pub fn to_json(&self) -> Result<String, PdfError> {
+        serde_json::to_string(self).map_err(|e| PdfError::CoreExceptionError(e.to_string()))
+    }
+}
@@ -0,0 +1,104 @@
+use serde::Deserialize;
+
+use crate::rect::Rect;
+
+/// TextSpan is a piece of text at its position in a PDF-document.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TextSpan {
+    #[serde(rename = "text")]
+    pub text: String,
+
+    /// The page number (1-based).
+    #[serde(rename = "page")]
+    pub page: i32,
+
+    /// The bounding box of the text on the page.
+    #[serde(rename = "rect")]
+    pub rect: Rect,
+
+    /// The character offset of the text in the extracted text of the page.
+    #[serde(rename = "offset")]
+    pub offset: usize,
+}
+
+/// TextChange is a difference between the texts of two PDF-documents.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type")]
+pub enum TextChange {
+    /// Text present only in the second PDF-document.
+    #[serde(rename = "insert")]
+    Insert {
+        #[serde(rename = "new")]
+        new: TextSpan,
+    },
+    /// Text present only in the first PDF-document.
+    #[serde(rename = "delete")]
+    Delete {
+        #[serde(rename = "old")]
+        old: TextSpan,
+    },
+    /// Text replaced by other text.
+    #[serde(rename = "modify")]
+    Modify {
+        #[serde(rename = "old")]
+        old: TextSpan,
+        #[serde(rename = "new")]
+        new: TextSpan,
+    },
+}
+
+impl TextChange {
+    /// Return the text in the first PDF-document, `None` for an insertion.
+    pub fn before(&self) -> Option<&TextSpan> {
+        match self {
+            TextChange::Insert { .. } => None,
+            TextChange::Delete { old } | TextChange::Modify { old, .. } => Some(old),
+        }
+    }
+
+    /// Return the text in the second PDF-document, `None` for a deletion.
+    pub fn after(&self) -> Option<&TextSpan> {
+        match self {
+            TextChange::Delete { .. } => None,
+            TextChange::Insert { new } | TextChange::Modify { new, .. } => Some(new),
+        }
+    }
+}
+
+/// TextDiff lists the changes found by `Document::compare_text`, in reading order.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct TextDiff {
+    #[serde(rename = "changes", default)]
+    pub changes: Vec<TextChange>,
+}
+
+impl TextDiff {
+    /// Return true if the texts are identical.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Return the insertions.
+    pub fn insertions(&self) -> impl Iterator<Item = &TextSpan> {
+        self.changes.iter().filter_map(|change| match change {
+            TextChange::Insert { new } => Some(new),
+            _ => None,
+        })
+    }
+
+    /// Return the deletions.
+    pub fn deletions(&self) -> impl Iterator<Item = &TextSpan> {
+        self.changes.iter().filter_map(|change| match change {
+            TextChange::Delete { old } => Some(old),
+            _ => None,
+        })
+    }
+
+    /// Return the modifications as `(old, new)` pairs.
+    pub fn modifications(&self) -> impl Iterator<Item = (&TextSpan, &TextSpan)> {
+        self.changes.iter().filter_map(|change| match change {
+            TextChange::Modify { old, new } => Some((old, new)),
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,159 @@
+use crate::enums::ImageFormat;
+#[cfg(feature = "core-preview")]
+use crate::rect::Rect;
+
+/// TileOptions defines the deep-zoom tile pyramid of a page produced by `Document::page_tiles`.
+#[derive(Debug, Clone, Copy)]
+pub struct TileOptions {
+    /// The width and height of the tiles in pixels.
+    pub tile_size: u32,
+
+    /// The number of pixels each tile shares with its neighbours.
+    pub overlap: u32,
+
+    /// The zoom of the most detailed level, `1.0` for 1 pixel per point (72 DPI).
+    pub zoom: f64,
+
+    pub format: ImageFormat,
+}
+
+impl Default for TileOptions {
+    fn default() -> Self {
+        TileOptions {
+            tile_size: 256,
+            overlap: 1,
+            zoom: 1.0,
+            format: ImageFormat::Png,
+        }
+    }
+}
+
+/// Tile is an encoded image of a deep-zoom tile.
+#[derive(Debug, Clone)]
+pub struct Tile {
+    /// The pyramid level, `0` for the 1x1 pixel level.
+    pub level: u32,
+
+    pub column: u32,
+
+    pub row: u32,
+
+    pub data: Vec<u8>,
+}
+
+/// TilePyramid describes the deep-zoom levels of a page, following the Deep Zoom Image (DZI) layout.
+#[derive(Debug, Clone, Copy)]
+pub struct TilePyramid {
+    /// The width of the most detailed level in pixels.
+    pub width: u32,
+
+    /// The height of the most detailed level in pixels.
+    pub height: u32,
+
+    pub tile_size: u32,
+
+    pub overlap: u32,
+
+    pub format: ImageFormat,
+}
+
+impl TilePyramid {
+    /// Return the most detailed level; each level below halves the size.
+    pub fn max_level(&self) -> u32 {
+        let max_dimension = self.width.max(self.height).max(1);
+        u32::BITS - (max_dimension - 1).leading_zeros()
+    }
+
+    /// Return the width and height of the level in pixels.
+    pub fn level_size(&self, level: u32) -> (u32, u32) {
+        let divisor = 1u64 << (self.max_level() - level.min(self.max_level()));
+        let size = |dimension: u32| (dimension as u64).div_ceil(divisor).max(1) as u32;
+        (size(self.width), size(self.height))
+    }
+
+    /// Return the number of tile columns and rows of the level, `(0, 0)` if `tile_size` is 0.
+    pub fn tile_count(&self, level: u32) -> (u32, u32) {
+        if self.tile_size == 0 {
+            return (0, 0);
+        }
+        let (width, height) = self.level_size(level);
+        (
+            width.div_ceil(self.tile_size),
+            height.div_ceil(self.tile_size),
+        )
+    }
+
+    /// Return the pixel bounds `(left, top, right, bottom)` of the tile in its level, including overlap.
+    /// The bounds are clamped to the level size.
+    pub fn tile_bounds(&self, level: u32, column: u32, row: u32) -> (u32, u32, u32, u32) {
+        let (width, height) = self.level_size(level);
+        let start = |index: u32, size: u32| {
+            index
+                .saturating_mul(self.tile_size)
+                .saturating_sub(self.overlap)
+                .min(size)
+        };
+        let end = |index: u32, size: u32| {
+            index
+                .saturating_add(1)
+                .saturating_mul(self.tile_size)
+                .saturating_add(self.overlap)
+                .min(size)
+        };
+        (
+            start(column, width),
+            start(row, height),
+            end(column, width),
+            end(row, height),
+        )
+    }
+
+    /// Return the file extension of the tiles.
+    pub fn extension(&self) -> &'static str {
+        extension(self.format)
+    }
+
+    /// Return the DZI descriptor (XML) of the pyramid.
+    pub fn to_dzi(&self) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <Image xmlns=\"http://schemas.microsoft.com/deepzoom/2008\" Format=\"{}\" Overlap=\"{}\" TileSize=\"{}\">\n  \
+             <Size Width=\"{}\" Height=\"{}\"/>\n\
+             </Image>\n",
+            self.extension(),
+            self.overlap,
+            self.tile_size,
+            self.width,
+            self.height
+        )
+    }
+
+    // Returns the region of the page covered by the tile and the zoom of its level.
+    // Level sizes are rounded up, so the region is clamped to the page.
+    #[cfg(feature = "core-preview")]
+    pub(crate) fn tile_region(
+        &self,
+        page_rect: &Rect,
+        zoom: f64,
+        level: u32,
+        column: u32,
+        row: u32,
+    ) -> (Rect, f64) {
+        let scale = zoom / (1u64 << (self.max_level() - level)) as f64;
+        let (left, top, right, bottom) = self.tile_bounds(level, column, row);
+        let x = |pixels: u32| (page_rect.llx + pixels as f64 / scale).min(page_rect.urx);
+        let y = |pixels: u32| (page_rect.ury - pixels as f64 / scale).max(page_rect.lly);
+        let region = Rect::new(x(left), y(bottom), x(right), y(top));
+        (region, scale)
+    }
+}
+
+// Returns the file extension of the image format.
+pub(crate) fn extension(format: ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Png => "png",
+        ImageFormat::Jpg => "jpg",
+        ImageFormat::Bmp => "bmp",
+        ImageFormat::Tiff => "tiff",
+    }
+}
@@ -8,3 +8,52 @@ macro_rules! debug_println {
         std::println!($($rest)*)
     }
 }
+
+#[cfg(feature = "core-preview")]
+use crate::errors::PdfError;
+
+// Expands a page range string (e.g. "-2,4,6-8,10-") into page numbers (1-based) in the listed order.
+// Open ranges extend to the first or the last page of the PDF-document; an empty string selects all pages.
+#[cfg(feature = "core-preview")]
+pub(crate) fn parse_page_range(page_range: &str, page_count: i32) -> Result<Vec<i32>, PdfError> {
+    if page_range.trim().is_empty() {
+        return Ok((1..=page_count).collect());
+    }
+
+    let invalid = |part: &str| {
+        PdfError::CoreExceptionError(format!(
+            "invalid page range {:?} for {} pages: {:?}",
+            page_range, page_count, part
+        ))
+    };
+    let page = |part: &str, value: &str, default: i32| -> Result<i32, PdfError> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(default);
+        }
+        match value.parse::<i32>() {
+            Ok(num) if (1..=page_count).contains(&num) => Ok(num),
+            _ => Err(invalid(part)),
+        }
+    };
+
+    let mut pages = Vec::new();
+    for part in page_range.split(',') {
+        let part = part.trim();
+        if part.is_empty() {
+            return Err(invalid(part));
+        }
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let first = page(part, first, 1)?;
+                let last = page(part, last, page_count)?;
+                if first > last {
+                    return Err(invalid(part));
+                }
+                pages.extend(first..=last);
+            }
+            None => pages.push(page(part, part, 1)?),
+        }
+    }
+    Ok(pages)
+}
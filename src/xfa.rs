@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+/// DroppedXfaField describes an XFA field that has no AcroForm equivalent after conversion.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DroppedXfaField {
+    /// The XFA SOM expression of the field (e.g. `form1[0].subform[0].notes[0]`).
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "reason")]
+    pub reason: String,
+}
+
+/// XfaConversionReport lists the result of `Document::convert_xfa_to_acroform`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct XfaConversionReport {
+    /// The fully qualified names of the AcroForm fields created from XFA fields.
+    #[serde(rename = "converted", default)]
+    pub converted_fields: Vec<String>,
+
+    /// The XFA fields that did not survive the conversion.
+    #[serde(rename = "dropped", default)]
+    pub dropped_fields: Vec<DroppedXfaField>,
+}
+
+impl XfaConversionReport {
+    /// Return true if every XFA field was converted.
+    pub fn is_lossless(&self) -> bool {
+        self.dropped_fields.is_empty()
+    }
+}